@@ -0,0 +1,33 @@
+use axum::{extract::Request, middleware::Next, response::Response};
+
+use crate::error::AppError;
+
+/// Header clients send the API key in
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// Compare two byte strings in time independent of where they first differ
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Middleware rejecting requests whose `x-api-key` header does not match the `API_KEY` env value.
+///
+/// When `API_KEY` is unset every request is rejected, so protected routes are never left open by accident.
+pub async fn require_api_key(request: Request, next: Next) -> Result<Response, AppError> {
+    let expected = std::env::var("API_KEY").ok().filter(|key| !key.is_empty());
+    let provided = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match (expected, provided) {
+        (Some(expected), Some(provided)) if constant_time_eq(expected.as_bytes(), provided.as_bytes()) => {
+            Ok(next.run(request).await)
+        }
+        (None, _) => {
+            tracing::warn!("Rejected {} {}: API_KEY is not configured", request.method(), request.uri());
+            Err(AppError::Unauthorized)
+        }
+        _ => Err(AppError::Unauthorized),
+    }
+}
@@ -1,19 +1,33 @@
 use axum::{
     extract::{Path, State, Query},
-    http::StatusCode,
-    response::Json,
-    routing::{get, post, put, delete},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware,
+    response::{IntoResponse, Json, Response},
+    routing::{get, patch, post, put, delete},
     Router,
 };
 use futures_util::TryStreamExt;
-use mongodb::{options::FindOptions, Collection, bson::{doc, oid::ObjectId}};
-use serde::{Deserialize, Serialize};
+use mongodb::{
+    error::ErrorKind,
+    options::{FindOptions, IndexOptions},
+    Collection, IndexModel,
+    bson::{doc, oid::ObjectId},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 
-use crate::{models::Competition, AppState, ApiResponse};
+use crate::{
+    auth::require_api_key,
+    error::{ApiJson, AppError},
+    models::Competition,
+    scrapers::{find_duplicate_competitions, find_similar_competitions, merge_sources},
+    stats::computed_status_expression,
+    AppState, ApiResponse,
+};
 
 /// Query parameters for filtering competitions
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct CompetitionQuery {
     #[serde(default)]
     pub page: Option<u32>,
@@ -27,8 +41,33 @@ pub struct CompetitionQuery {
     pub date_from: Option<String>,
     #[serde(default)]
     pub date_to: Option<String>,
+    #[serde(default)]
+    pub upcoming: Option<bool>,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub search: Option<String>,
+    #[serde(default)]
+    pub sort_by: Option<String>,
+    #[serde(default)]
+    pub sort_order: Option<String>,
+    #[serde(default)]
+    pub include_deleted: Option<bool>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub has_registration_link: Option<bool>,
+    #[serde(default)]
+    pub deadline_after: Option<String>,
+    #[serde(default)]
+    pub deadline_before: Option<String>,
+    #[serde(default)]
+    pub include_no_deadline: Option<bool>,
 }
 
+/// Fields the competitions list can be sorted by
+const SORTABLE_FIELDS: &[&str] = &["date", "name", "host", "created_at"];
+
 /// Response for paginated results
 #[derive(Serialize)]
 pub struct PaginatedResponse<T> {
@@ -36,288 +75,1147 @@ pub struct PaginatedResponse<T> {
     pub page: u32,
     pub limit: u32,
     pub total: u64,
+    pub total_pages: u32,
+    pub has_next: bool,
+    pub has_prev: bool,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Wrap one page of results, deriving the page count and navigation flags from the totals
+    pub fn new(data: Vec<T>, page: u32, limit: u32, total: u64) -> Self {
+        let total_pages = if limit == 0 {
+            0
+        } else {
+            total.div_ceil(u64::from(limit)).try_into().unwrap_or(u32::MAX)
+        };
+        
+        PaginatedResponse {
+            data,
+            page,
+            limit,
+            total,
+            total_pages,
+            has_next: page < total_pages,
+            has_prev: page > 1,
+        }
+    }
+    
+    /// Transform each item of the page, keeping the pagination fields
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PaginatedResponse<U> {
+        PaginatedResponse {
+            data: self.data.into_iter().map(f).collect(),
+            page: self.page,
+            limit: self.limit,
+            total: self.total,
+            total_pages: self.total_pages,
+            has_next: self.has_next,
+            has_prev: self.has_prev,
+        }
+    }
+}
+
+/// Fetch one page of the documents matching `filter` in `sort` order, along with the total count.
+///
+/// `page` starts at 1 and defaults to it; `limit` should already be clamped with `PaginationConfig::limit`.
+pub(crate) async fn paginate<T>(
+    collection: &Collection<T>,
+    filter: mongodb::bson::Document,
+    page: Option<u32>,
+    limit: u32,
+    sort: mongodb::bson::Document,
+) -> Result<PaginatedResponse<T>, AppError>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    let page = page.unwrap_or(1).max(1);
+    
+    let total = collection
+        .count_documents(filter.clone())
+        .await
+        .map_err(|e| AppError::database(&format!("Error counting {}", collection.name()), e))?;
+    
+    let items: Vec<T> = collection
+        .find(filter)
+        .with_options(create_pagination_options(page, limit, sort))
+        .await
+        .map_err(|e| AppError::database(&format!("Error finding {}", collection.name()), e))?
+        .try_collect()
+        .await
+        .map_err(|e| AppError::database(&format!("Error fetching {} from cursor", collection.name()), e))?;
+    
+    Ok(PaginatedResponse::new(items, page, limit, total))
+}
+
+/// Page size settings shared by the paginated list endpoints
+#[derive(Debug, Clone, Copy)]
+pub struct PaginationConfig {
+    pub default_limit: u32,
+    pub max_limit: u32,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        PaginationConfig {
+            default_limit: 10,
+            max_limit: 100,
+        }
+    }
+}
+
+impl PaginationConfig {
+    /// Build the config from `DEFAULT_PAGE_LIMIT` and `MAX_PAGE_LIMIT`, falling back to defaults
+    pub fn from_env() -> Self {
+        let mut config = PaginationConfig::default();
+        let number = |key: &str| std::env::var(key).ok().and_then(|v| v.parse::<u32>().ok());
+        
+        if let Some(max_limit) = number("MAX_PAGE_LIMIT").filter(|max| *max > 0) {
+            config.max_limit = max_limit;
+        }
+        if let Some(default_limit) = number("DEFAULT_PAGE_LIMIT").filter(|limit| *limit > 0) {
+            config.default_limit = default_limit;
+        }
+        config
+    }
+    
+    /// Page size for a request, clamping anything above the maximum instead of rejecting it
+    pub fn limit(&self, requested: Option<u32>) -> u32 {
+        requested.unwrap_or(self.default_limit).min(self.max_limit)
+    }
+}
+
+/// Competitions featured at once unless `MAX_FEATURED` says otherwise
+const DEFAULT_MAX_FEATURED: usize = 1;
+
+/// Read the most competitions that may be featured at once from `MAX_FEATURED`, falling back to 1
+pub fn max_featured_from_env() -> usize {
+    std::env::var("MAX_FEATURED")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|max| *max > 0)
+        .unwrap_or(DEFAULT_MAX_FEATURED)
 }
 
-/// Helper function to create MongoDB filter from query parameters using functional approach
-fn build_competition_filter(query: &CompetitionQuery) -> mongodb::bson::Document {
+/// Parse an optional RFC3339 date query parameter, treating an empty value as absent
+fn parse_date_param(name: &str, value: Option<&str>) -> Result<Option<DateTime<Utc>>, AppError> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        None => Ok(None),
+        Some(value) => value.parse::<DateTime<Utc>>().map(Some).map_err(|_| {
+            AppError::BadRequest(format!(
+                "Invalid {name} '{value}': expected an RFC3339 date such as 2024-03-01T00:00:00Z"
+            ))
+        }),
+    }
+}
+
+/// Helper function to create MongoDB filter from query parameters using functional approach.
+/// Malformed date parameters are rejected rather than ignored.
+pub(crate) fn build_competition_filter(query: &CompetitionQuery) -> Result<mongodb::bson::Document, AppError> {
     let mut filter = doc! {};
+    let now = Utc::now();
     
     // Using functional approach to apply filters
     let filters = vec![
-        query.status.as_ref().map(|status| ("status", status.as_str())),
         query.host.as_ref().map(|host| ("host", host.as_str())),
     ];
     
-    for filter_opt in filters {
-        if let Some((key, value)) = filter_opt {
-            filter.insert(key, value);
+    for (key, value) in filters.into_iter().flatten() {
+        filter.insert(key, value);
+    }
+    
+    // Responses show the status derived from the dates, so filter on the same derivation
+    if let Some(status) = &query.status {
+        filter.insert("$expr", doc! { "$eq": [computed_status_expression(&now), status] });
+    }
+    
+    // Match a single token within the comma-joined source list, e.g. "CTFTime" in "HKU, CTFTime"
+    if let Some(source) = query.source.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        let pattern = format!(r"(^|,)\s*{}\s*(,|$)", regex::escape(source));
+        filter.insert("source", doc! { "$regex": pattern, "$options": "i" });
+    }
+    
+    // Archived competitions are hidden unless explicitly requested
+    if query.include_deleted != Some(true) {
+        filter.insert("deleted_at", mongodb::bson::Bson::Null);
+    }
+    
+    // Comma-separated tags must all be present, e.g. "ctf,online"
+    if let Some(tag) = query.tag.as_deref() {
+        let tags: Vec<String> = tag
+            .split(',')
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        if !tags.is_empty() {
+            filter.insert("tags", doc! { "$all": tags });
         }
     }
     
-    // Handle date filters separately since they require parsing
-    if let Some(date_from) = &query.date_from {
-        if let Ok(from_date) = date_from.parse::<DateTime<Utc>>() {
-            // Convert to BsonDateTime for MongoDB using timestamp milliseconds
-            let bson_date = mongodb::bson::DateTime::from_millis(from_date.timestamp_millis());
-            filter.insert("date", doc! { "$gte": bson_date });
+    // `true` keeps competitions with a non-empty link, `false` those missing one
+    match query.has_registration_link {
+        Some(true) => {
+            filter.insert("registration_link", doc! { "$exists": true, "$nin": [null, ""] });
+        }
+        Some(false) => {
+            filter.insert("registration_link", doc! { "$in": [null, ""] });
         }
+        None => {}
     }
     
-    if let Some(date_to) = &query.date_to {
-        if let Ok(to_date) = date_to.parse::<DateTime<Utc>>() {
-            // Convert to BsonDateTime for MongoDB using timestamp milliseconds
-            let bson_date = mongodb::bson::DateTime::from_millis(to_date.timestamp_millis());
-            match filter.get_mut("date") {
-                Some(mongodb::bson::Bson::Document(date_doc)) => {
-                    date_doc.insert("$lte", bson_date);
-                }
-                _ => {
-                    filter.insert("date", doc! { "$lte": bson_date });
-                }
-            }
+    // Keyword search across name and description using the text index
+    if let Some(search) = query.search.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        filter.insert("$text", doc! { "$search": search });
+    }
+    
+    // Handle date filters separately since they require parsing.
+    // `upcoming` raises the lower bound to now (keeping a later `date_from`), while `date_to`
+    // always sets the upper bound, so all three combine into a single range on `date`.
+    let mut date_from = parse_date_param("date_from", query.date_from.as_deref())?;
+    if query.upcoming == Some(true) {
+        date_from = Some(date_from.map_or(now, |from| from.max(now)));
+    }
+    let date_to = parse_date_param("date_to", query.date_to.as_deref())?;
+    
+    // Dates are stored as RFC3339 strings, so compare against the same representation
+    let mut date_range = doc! {};
+    if let Some(from_date) = date_from {
+        date_range.insert("$gte", from_date.to_rfc3339());
+    }
+    if let Some(to_date) = date_to {
+        date_range.insert("$lte", to_date.to_rfc3339());
+    }
+    if !date_range.is_empty() {
+        filter.insert("date", date_range);
+    }
+    
+    // Each alternative set goes in its own clause so upcoming and deadline filters combine
+    let mut clauses = Vec::new();
+    
+    // Upcoming competitions must also still be open for signup when a deadline is known
+    if query.upcoming == Some(true) {
+        clauses.push(doc! { "$or": [
+            { "signup_deadline": { "$exists": false } },
+            { "signup_deadline": null },
+            { "signup_deadline": { "$gte": now.to_rfc3339() } },
+        ] });
+    }
+    
+    // Deadline range; competitions without a deadline only match with `include_no_deadline`
+    let deadline_after = parse_date_param("deadline_after", query.deadline_after.as_deref())?;
+    let deadline_before = parse_date_param("deadline_before", query.deadline_before.as_deref())?;
+    let mut deadline_range = doc! {};
+    if let Some(after) = deadline_after {
+        deadline_range.insert("$gte", after.to_rfc3339());
+    }
+    if let Some(before) = deadline_before {
+        deadline_range.insert("$lte", before.to_rfc3339());
+    }
+    if !deadline_range.is_empty() {
+        let in_range = doc! { "signup_deadline": deadline_range };
+        if query.include_no_deadline == Some(true) {
+            clauses.push(doc! { "$or": [
+                in_range,
+                { "signup_deadline": { "$exists": false } },
+                { "signup_deadline": null },
+            ] });
+        } else {
+            clauses.push(in_range);
         }
     }
     
-    filter
+    if !clauses.is_empty() {
+        filter.insert("$and", clauses);
+    }
+    
+    Ok(filter)
+}
+
+/// Indexes on the competitions collection: the filterable fields (`tags` is multikey), the text index used by `search`,
+/// and unique indexes on `slug` and on `external_id` for competitions that have them
+pub fn competition_indexes() -> Vec<IndexModel> {
+    let single_field = ["status", "host", "date", "tags", "created_at"].map(|field| {
+        IndexModel::builder()
+            .keys(doc! { field: 1 })
+            .options(
+                IndexOptions::builder()
+                    .name(format!("{field}_1"))
+                    .build(),
+            )
+            .build()
+    });
+    let text = IndexModel::builder()
+        .keys(doc! { "name": "text", "description": "text" })
+        .options(
+            IndexOptions::builder()
+                .name("name_description_text".to_string())
+                .build(),
+        )
+        .build();
+    
+    let slug = IndexModel::builder()
+        .keys(doc! { "slug": 1 })
+        .options(
+            IndexOptions::builder()
+                .name("slug_1".to_string())
+                .unique(true)
+                .partial_filter_expression(doc! { "slug": { "$type": "string" } })
+                .build(),
+        )
+        .build();
+    let external_id = IndexModel::builder()
+        .keys(doc! { "external_id": 1 })
+        .options(
+            IndexOptions::builder()
+                .name("external_id_1".to_string())
+                .unique(true)
+                .partial_filter_expression(doc! { "external_id": { "$type": "string" } })
+                .build(),
+        )
+        .build();
+    
+    single_field.into_iter().chain([text, slug, external_id]).collect()
 }
 
 /// Helper function to get collection reference
-fn get_competition_collection(state: &AppState) -> Collection<Competition> {
+pub(crate) fn get_competition_collection(state: &AppState) -> Collection<Competition> {
     state.db.collection("competitions")
 }
 
+/// Helper function to build the sort document from query parameters, defaulting to date ascending
+pub(crate) fn build_competition_sort(query: &CompetitionQuery) -> Result<mongodb::bson::Document, AppError> {
+    let field = query.sort_by.as_deref().unwrap_or("date");
+    if !SORTABLE_FIELDS.contains(&field) {
+        return Err(AppError::BadRequest(format!(
+            "Invalid sort_by '{field}': expected one of {}",
+            SORTABLE_FIELDS.join(", ")
+        )));
+    }
+    
+    let direction = match query.sort_order.as_deref().unwrap_or("asc") {
+        "asc" => 1,
+        "desc" => -1,
+        other => {
+            return Err(AppError::BadRequest(format!(
+                "Invalid sort_order '{other}': expected asc or desc"
+            )));
+        }
+    };
+    
+    Ok(doc! { field: direction })
+}
+
 /// Helper function to create pagination options
-fn create_pagination_options(page: u32, limit: u32) -> FindOptions {
-    let skip = (page.saturating_sub(1)) * limit;
+fn create_pagination_options(page: u32, limit: u32, sort: mongodb::bson::Document) -> FindOptions {
+    // Widened so a huge page number cannot overflow the skip count
+    let skip = u64::from(page.saturating_sub(1)) * u64::from(limit);
     
     FindOptions::builder()
-        .skip(Some(skip as u64))
+        .skip(Some(skip))
         .limit(Some(limit as i64))
-        .sort(Some(doc! { "date": 1 }))
+        .sort(Some(sort))
         .build()
 }
 
+/// Replace the stored status with one derived from the competition's dates
+pub(crate) fn with_computed_status(mut competition: Competition) -> Competition {
+    competition.status = Some(competition.computed_status().to_string());
+    competition
+}
+
+/// Strong ETag for a response body, derived from a hash of its JSON serialization
+fn compute_etag<T: Serialize>(body: &T) -> Option<HeaderValue> {
+    use std::hash::{Hash, Hasher};
+    
+    let json = serde_json::to_vec(body).ok()?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    json.hash(&mut hasher);
+    HeaderValue::from_str(&format!("\"{:016x}\"", hasher.finish())).ok()
+}
+
+/// Whether the request's `If-None-Match` header matches the given ETag.
+///
+/// Uses the weak comparison GET requires, so a `W/` prefix on either side is ignored.
+fn if_none_match_matches(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let strip_weak = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let Ok(etag) = etag.to_str().map(strip_weak) else {
+        return false;
+    };
+    
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|tag| tag.trim() == "*" || strip_weak(tag) == etag)
+}
+
+/// Give each competition a slug not used by any stored competition or earlier one in the batch,
+/// appending `-2`, `-3`, ... to the name-derived slug on collision
+pub(crate) async fn assign_slugs(
+    collection: &Collection<Competition>,
+    competitions: &mut [Competition],
+) -> Result<(), mongodb::error::Error> {
+    let mut taken: std::collections::HashSet<String> = std::collections::HashSet::new();
+    
+    for competition in competitions.iter_mut() {
+        let base = competition.base_slug();
+        let pattern = format!(r"^{}(-\d+)?$", regex::escape(&base));
+        let stored: Vec<String> = collection
+            .distinct("slug", doc! { "slug": { "$regex": pattern } })
+            .await?
+            .into_iter()
+            .filter_map(|slug| slug.as_str().map(str::to_string))
+            .collect();
+        taken.extend(stored);
+        
+        let slug = std::iter::once(base.clone())
+            .chain((2..).map(|n| format!("{base}-{n}")))
+            .find(|slug| !taken.contains(slug))
+            .unwrap_or(base);
+        taken.insert(slug.clone());
+        competition.slug = slug;
+    }
+    Ok(())
+}
+
+/// `201 Created` response with a `Location` header pointing at the new resource
+pub(crate) fn created_response<T: Serialize>(location: Option<String>, body: ApiResponse<T>) -> Response {
+    let location = location.and_then(|location| HeaderValue::from_str(&location).ok());
+    match location {
+        Some(location) => (StatusCode::CREATED, [(header::LOCATION, location)], Json(body)).into_response(),
+        None => (StatusCode::CREATED, Json(body)).into_response(),
+    }
+}
+
 /// Functional helper to process results from MongoDB cursor
-async fn process_competition_cursor(
+pub(crate) async fn process_competition_cursor(
     mut cursor: mongodb::Cursor<Competition>
-) -> Result<Vec<Competition>, StatusCode> {
+) -> Result<Vec<Competition>, AppError> {
     let mut competitions = Vec::new();
     while let Some(competition) = cursor
         .try_next()
         .await
-        .map_err(|e| {
-            tracing::error!("Error fetching competition from cursor: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
+        .map_err(|e| AppError::database("Error fetching competition from cursor", e))?
     {
         competitions.push(competition);
     }
     Ok(competitions)
 }
 
+/// Open a cursor over every competition matching the query filters and sort order, without pagination
+pub(crate) async fn find_competitions_cursor(
+    state: &AppState,
+    query: &CompetitionQuery,
+) -> Result<mongodb::Cursor<Competition>, AppError> {
+    let filter = build_competition_filter(query)?;
+    let options = FindOptions::builder()
+        .sort(Some(build_competition_sort(query)?))
+        .build();
+    
+    get_competition_collection(state)
+        .find(filter)
+        .with_options(options)
+        .await
+        .map_err(|e| AppError::database("Error finding competitions", e))
+}
+
+/// Fetch every competition matching the query filters and sort order, without pagination
+pub(crate) async fn find_all_competitions(
+    state: &AppState,
+    query: &CompetitionQuery,
+) -> Result<Vec<Competition>, AppError> {
+    let cursor = find_competitions_cursor(state, query).await?;
+    
+    Ok(process_competition_cursor(cursor)
+        .await?
+        .into_iter()
+        .map(with_computed_status)
+        .collect())
+}
+
 /// Get all competitions with optional filtering and pagination
 pub async fn get_competitions(
     State(state): State<AppState>,
     query: Option<Query<CompetitionQuery>>,
-) -> Result<Json<ApiResponse<PaginatedResponse<Competition>>>, StatusCode> {
-    let collection = get_competition_collection(&state);
-    
-    let query_params = query.unwrap_or(Query(CompetitionQuery {
-        page: None,
-        limit: None,
-        status: None,
-        host: None,
-        date_from: None,
-        date_to: None,
-    }));
+) -> Result<Json<ApiResponse<PaginatedResponse<Competition>>>, AppError> {
+    let query_params = query.unwrap_or_default();
     
-    let filter = build_competition_filter(&query_params.0);
+    let filter = build_competition_filter(&query_params.0)?;
+    let sort = build_competition_sort(&query_params.0)?;
+    let limit = state.pagination.limit(query_params.limit);
     
-    // Pagination
-    let page = query_params.page.unwrap_or(1).max(1);
-    let limit = query_params.limit.unwrap_or(10).min(100); // Max 100 per page
+    let paginated_response = paginate(&get_competition_collection(&state), filter, query_params.page, limit, sort)
+        .await?
+        .map(with_computed_status);
     
-    let options = create_pagination_options(page, limit);
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(paginated_response),
+        message: Some("Competitions retrieved successfully".to_string()),
+    }))
+}
+
+/// Count competitions matching the list filters without fetching any documents
+pub async fn count_competitions(
+    State(state): State<AppState>,
+    query: Option<Query<CompetitionQuery>>,
+) -> Result<Json<ApiResponse<u64>>, AppError> {
+    let query_params = query.unwrap_or_default();
+    let filter = build_competition_filter(&query_params.0)?;
     
-    // Get total count using functional composition
-    let total = collection
-        .count_documents(filter.clone())
+    let total = get_competition_collection(&state)
+        .count_documents(filter)
         .await
-        .map_err(|e| {
-            tracing::error!("Error counting competitions: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+        .map_err(|e| AppError::database("Error counting competitions", e))?;
     
-    // Get competitions using functional approach
-    let cursor = collection
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(total),
+        message: Some("Competitions counted successfully".to_string()),
+    }))
+}
+
+/// Query parameters for the calendar endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct CalendarQuery {
+    /// Month to show as `YYYY-MM`, defaulting to the current month
+    #[serde(default)]
+    pub month: Option<String>,
+    #[serde(default)]
+    pub host: Option<String>,
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// First instant of the `YYYY-MM` month and of the month after it
+fn month_bounds(month: &str) -> Result<(DateTime<Utc>, DateTime<Utc>), AppError> {
+    let invalid = || AppError::BadRequest(format!("Invalid month '{month}': expected YYYY-MM such as 2024-03"));
+    let start = chrono::NaiveDate::parse_from_str(&format!("{}-01", month.trim()), "%Y-%m-%d").map_err(|_| invalid())?;
+    let end = start.checked_add_months(chrono::Months::new(1)).ok_or_else(invalid)?;
+    
+    Ok((
+        start.and_time(chrono::NaiveTime::MIN).and_utc(),
+        end.and_time(chrono::NaiveTime::MIN).and_utc(),
+    ))
+}
+
+/// Competitions starting in a month, keyed by `YYYY-MM-DD` and sorted by date within each day.
+///
+/// Only the requested month is queried; days without competitions are left out, so a quiet month is an empty map.
+pub async fn get_competition_calendar(
+    State(state): State<AppState>,
+    query: Option<Query<CalendarQuery>>,
+) -> Result<Json<ApiResponse<std::collections::BTreeMap<String, Vec<Competition>>>>, AppError> {
+    let CalendarQuery { month, host, source } = query.map(|q| q.0).unwrap_or_default();
+    let month = month.unwrap_or_else(|| Utc::now().format("%Y-%m").to_string());
+    let (start, end) = month_bounds(&month)?;
+    
+    let mut filter = build_competition_filter(&CompetitionQuery {
+        host,
+        source,
+        ..Default::default()
+    })?;
+    // Dates are stored as RFC3339 strings, so compare against the same representation
+    filter.insert("date", doc! { "$gte": start.to_rfc3339(), "$lt": end.to_rfc3339() });
+    
+    let cursor = get_competition_collection(&state)
         .find(filter)
-        .with_options(options)
+        .sort(doc! { "date": 1 })
         .await
-        .map_err(|e| {
-            tracing::error!("Error finding competitions: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
-    
-    let competitions = process_competition_cursor(cursor).await?;
-    
-    let paginated_response = PaginatedResponse {
-        data: competitions,
-        page,
-        limit,
-        total,
-    };
+        .map_err(|e| AppError::database("Error finding competitions for calendar", e))?;
+    
+    let mut days: std::collections::BTreeMap<String, Vec<Competition>> = std::collections::BTreeMap::new();
+    for competition in process_competition_cursor(cursor).await? {
+        days.entry(competition.date.format("%Y-%m-%d").to_string())
+            .or_default()
+            .push(with_computed_status(competition));
+    }
     
     Ok(Json(ApiResponse {
         success: true,
-        data: Some(paginated_response),
-        message: Some("Competitions retrieved successfully".to_string()),
+        data: Some(days),
+        message: Some(format!("Competitions for {month} retrieved successfully")),
+    }))
+}
+
+/// Get a competition by its slug
+pub async fn get_competition_by_slug(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Result<Json<ApiResponse<Competition>>, AppError> {
+    let competition = get_competition_collection(&state)
+        .find_one(doc! { "slug": slug.to_lowercase() })
+        .await
+        .map_err(|e| AppError::database("Error finding competition by slug", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(with_computed_status(competition)),
+        message: Some("Competition retrieved successfully".to_string()),
     }))
 }
 
 /// Get a specific competition by ID
+///
+/// Responses carry an `ETag`; a matching `If-None-Match` gets `304 Not Modified` with no body.
 pub async fn get_competition_by_id(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> Result<Json<ApiResponse<Competition>>, StatusCode> {
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     let collection = get_competition_collection(&state);
     
     // Validate and convert string ID to ObjectId
-    let object_id = ObjectId::parse_str(&id)
-        .map_err(|e| {
-            tracing::error!("Invalid ObjectId: {}", e);
-            StatusCode::BAD_REQUEST
-        })?;
+    let object_id = AppError::parse_object_id(&id)?;
     
-    match collection
+    let competition = collection
         .find_one(doc! { "_id": object_id })
         .await
-        .map_err(|e| {
-            tracing::error!("Error finding competition by ID: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
-    {
-        Some(competition) => Ok(Json(ApiResponse {
-            success: true,
-            data: Some(competition),
-            message: Some("Competition retrieved successfully".to_string()),
-        })),
-        None => Err(StatusCode::NOT_FOUND),
+        .map_err(|e| AppError::database("Error finding competition by ID", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    let body = ApiResponse {
+        success: true,
+        data: Some(with_computed_status(competition)),
+        message: Some("Competition retrieved successfully".to_string()),
+    };
+    let Some(etag) = compute_etag(&body) else {
+        return Ok(Json(body).into_response());
+    };
+    
+    if if_none_match_matches(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response());
     }
+    Ok(([(header::ETAG, etag)], Json(body)).into_response())
 }
 
-/// Create a new competition
+/// Create a new competition, responding `201 Created` with its URL in `Location`
 pub async fn create_competition(
     State(state): State<AppState>,
-    Json(mut competition): Json<Competition>,
-) -> Result<Json<ApiResponse<Competition>>, StatusCode> {
+    ApiJson(mut competition): ApiJson<Competition>,
+) -> Result<Response, AppError> {
+    competition.validate().map_err(AppError::Validation)?;
+    competition.normalize_tags();
+    
     let collection = get_competition_collection(&state);
     
     // Set ID to None so MongoDB generates a new one
     competition.id = None;
+    competition.mark_created();
+    // Featuring goes through PATCH /competitions/:id/feature so the limit is enforced
+    competition.featured = false;
+    competition.featured_at = None;
+    assign_slugs(&collection, std::slice::from_mut(&mut competition))
+        .await
+        .map_err(|e| AppError::database("Failed to generate competition slug", e))?;
     
-    match collection
+    let mongodb::results::InsertOneResult { inserted_id, .. } = collection
         .insert_one(competition.clone())
         .await
-        .map_err(|e| {
-            tracing::error!("Failed to insert competition: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
-    {
-        mongodb::results::InsertOneResult { inserted_id, .. } => {
-            // Set the generated ID in the response
-            let mut competition_with_id = competition;
-            if let Some(id) = inserted_id.as_object_id() {
-                competition_with_id.id = Some(id);
+        .map_err(|e| AppError::database("Failed to insert competition", e))?;
+    
+    // Set the generated ID in the response
+    let mut competition_with_id = competition;
+    if let Some(id) = inserted_id.as_object_id() {
+        competition_with_id.id = Some(id);
+    }
+    state.notifier.notify_new_competitions(std::slice::from_ref(&competition_with_id));
+    
+    let location = competition_with_id.id.map(|id| format!("/competitions/{}", id.to_hex()));
+    Ok(created_response(location, ApiResponse {
+        success: true,
+        data: Some(competition_with_id),
+        message: Some("Competition created successfully".to_string()),
+    }))
+}
+
+/// Query parameters for the similar competitions endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct SimilarQuery {
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// A competition that fuzzy-matches another, with its similarity score from 0.0 to 1.0
+#[derive(Debug, Serialize)]
+pub struct SimilarCompetition {
+    #[serde(flatten)]
+    pub competition: Competition,
+    pub score: f64,
+}
+
+/// Get stored competitions whose names fuzzy-match the given competition, best match first
+pub async fn get_similar_competitions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    query: Option<Query<SimilarQuery>>,
+) -> Result<Json<ApiResponse<Vec<SimilarCompetition>>>, AppError> {
+    let object_id = AppError::parse_object_id(&id)?;
+    let limit = query.and_then(|q| q.limit).unwrap_or(5).min(50);
+    
+    let competition = get_competition_collection(&state)
+        .find_one(doc! { "_id": object_id })
+        .await
+        .map_err(|e| AppError::database("Error finding competition by ID", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    let similar = find_similar_competitions(&state.db, &competition, state.scrapers.match_config())
+        .await
+        .map_err(|e| AppError::database("Error finding similar competitions", e))?
+        .into_iter()
+        .take(limit)
+        .map(|(competition, score)| SimilarCompetition {
+            competition: with_computed_status(competition),
+            score,
+        })
+        .collect();
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(similar),
+        message: Some("Similar competitions retrieved successfully".to_string()),
+    }))
+}
+
+/// A likely duplicate of another competition, for reviewing before calling the merge endpoint
+#[derive(Debug, Serialize)]
+pub struct DuplicateCandidate {
+    pub id: String,
+    pub name: String,
+    pub similarity: f64,
+    /// Fields whose values differ between the two competitions
+    pub differing_fields: Vec<&'static str>,
+}
+
+/// Names of the fields that differ between two competitions, ignoring ids and bookkeeping timestamps
+fn differing_fields(a: &Competition, b: &Competition) -> Vec<&'static str> {
+    let comparisons = [
+        ("name", a.name != b.name),
+        ("date", a.date != b.date),
+        ("end_date", a.end_date != b.end_date),
+        ("host", a.host != b.host),
+        ("source", a.source != b.source),
+        ("description", a.description != b.description),
+        ("signup_deadline", a.signup_deadline != b.signup_deadline),
+        ("location", a.location != b.location),
+        ("registration_link", a.registration_link != b.registration_link),
+        ("max_participants", a.max_participants != b.max_participants),
+        ("status", a.status != b.status),
+        ("tags", a.tags != b.tags),
+        ("external_id", a.external_id != b.external_id),
+    ];
+    comparisons
+        .into_iter()
+        .filter(|(_, differs)| *differs)
+        .map(|(field, _)| field)
+        .collect()
+}
+
+/// Get the stored competitions the deduplicator considers the same event as the given one,
+/// best match first, with the fields that differ
+pub async fn get_duplicate_competitions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<DuplicateCandidate>>>, AppError> {
+    let object_id = AppError::parse_object_id(&id)?;
+    
+    let competition = get_competition_collection(&state)
+        .find_one(doc! { "_id": object_id })
+        .await
+        .map_err(|e| AppError::database("Error finding competition by ID", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    let duplicates: Vec<DuplicateCandidate> =
+        find_duplicate_competitions(&state.db, &competition, state.scrapers.match_config())
+            .await
+            .map_err(|e| AppError::database("Error finding duplicate competitions", e))?
+            .into_iter()
+            .map(|(candidate, similarity)| DuplicateCandidate {
+                id: candidate.id.map(|id| id.to_hex()).unwrap_or_default(),
+                differing_fields: differing_fields(&competition, &candidate),
+                name: candidate.name,
+                similarity,
+            })
+            .collect();
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!("Found {} possible duplicates", duplicates.len())),
+        data: Some(duplicates),
+    }))
+}
+
+/// Outcome of one competition in a bulk create request
+#[derive(Debug, Serialize)]
+pub struct BulkCreateResult {
+    pub index: usize,
+    pub id: Option<String>,
+    pub errors: Vec<String>,
+}
+
+/// Create many competitions at once, skipping invalid items instead of failing the whole batch
+pub async fn bulk_create_competitions(
+    State(state): State<AppState>,
+    ApiJson(competitions): ApiJson<Vec<Competition>>,
+) -> Result<Json<ApiResponse<Vec<BulkCreateResult>>>, AppError> {
+    let mut results = Vec::with_capacity(competitions.len());
+    let mut valid = Vec::new();
+    
+    for (index, mut competition) in competitions.into_iter().enumerate() {
+        match competition.validate() {
+            Ok(()) => {
+                competition.id = None;
+                competition.normalize_tags();
+                competition.mark_created();
+                competition.featured = false;
+                competition.featured_at = None;
+                valid.push((index, competition));
+            }
+            Err(errors) => results.push(BulkCreateResult { index, id: None, errors }),
+        }
+    }
+    
+    if !valid.is_empty() {
+        let (indices, mut documents): (Vec<usize>, Vec<Competition>) = valid.into_iter().unzip();
+        let collection = get_competition_collection(&state);
+        assign_slugs(&collection, &mut documents)
+            .await
+            .map_err(|e| AppError::database("Failed to generate competition slugs", e))?;
+        for document in &mut documents {
+            document.id = Some(ObjectId::new());
+        }
+        
+        // Unordered, so a unique-index collision only fails its own document rather than the rest of the batch
+        let failed = match collection.insert_many(&documents).ordered(false).await {
+            Ok(_) => HashMap::new(),
+            Err(e) => insert_write_errors(&e)
+                .ok_or_else(|| AppError::database("Failed to insert competitions", e))?,
+        };
+        
+        // Write errors are keyed by position within the inserted batch, not the request
+        let mut created = Vec::with_capacity(documents.len());
+        for (position, (index, document)) in indices.into_iter().zip(documents).enumerate() {
+            match failed.get(&position) {
+                Some(error) => results.push(BulkCreateResult { index, id: None, errors: vec![error.clone()] }),
+                None => {
+                    let id = document.id.map(|id| id.to_hex());
+                    results.push(BulkCreateResult { index, id, errors: Vec::new() });
+                    created.push(document);
+                }
+            }
+        }
+        state.notifier.notify_new_competitions(&created);
+    }
+    
+    results.sort_by_key(|result| result.index);
+    let created = results.iter().filter(|result| result.id.is_some()).count();
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!("{} of {} competitions created", created, results.len())),
+        data: Some(results),
+    }))
+}
+
+/// Per-document messages from an unordered `insert_many`, keyed by batch position, or `None` when the
+/// failure was not confined to individual documents
+pub(crate) fn insert_write_errors(error: &mongodb::error::Error) -> Option<HashMap<usize, String>> {
+    let ErrorKind::InsertMany(insert_error) = error.kind.as_ref() else {
+        return None;
+    };
+    if insert_error.write_concern_error.is_some() {
+        return None;
+    }
+    
+    let write_errors = insert_error.write_errors.as_ref()?;
+    Some(
+        write_errors
+            .iter()
+            .map(|write_error| {
+                let message = if write_error.code == 11000 {
+                    "A competition with this slug or external_id already exists".to_string()
+                } else {
+                    write_error.message.clone()
+                };
+                (write_error.index, message)
+            })
+            .collect(),
+    )
+}
+
+/// A backup record that was not imported, with why
+#[derive(Debug, Serialize)]
+pub struct ImportSkipped {
+    pub index: usize,
+    pub errors: Vec<String>,
+}
+
+/// Outcome of importing a JSON backup
+#[derive(Debug, Default, Serialize)]
+pub struct ImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub skipped_records: Vec<ImportSkipped>,
+}
+
+/// Find the stored competition a backup record corresponds to, matching on `external_id`,
+/// then `slug`, then `_id`
+async fn find_imported_match(
+    collection: &Collection<Competition>,
+    competition: &Competition,
+) -> Result<Option<Competition>, mongodb::error::Error> {
+    let mut filters = Vec::new();
+    if let Some(external_id) = &competition.external_id {
+        filters.push(doc! { "external_id": external_id });
+    }
+    if !competition.slug.is_empty() {
+        filters.push(doc! { "slug": &competition.slug });
+    }
+    if let Some(id) = competition.id {
+        filters.push(doc! { "_id": id });
+    }
+    
+    for filter in filters {
+        if let Some(existing) = collection.find_one(filter).await? {
+            return Ok(Some(existing));
+        }
+    }
+    Ok(None)
+}
+
+/// Import a JSON array produced by `GET /competitions/export.json`, replacing competitions that
+/// match on `external_id`, `slug` or `_id` and inserting the rest, so importing twice changes nothing.
+///
+/// Records that fail to parse or validate are skipped and reported by index without aborting the import.
+pub async fn import_competitions(
+    State(state): State<AppState>,
+    ApiJson(records): ApiJson<Vec<serde_json::Value>>,
+) -> Result<Json<ApiResponse<ImportSummary>>, AppError> {
+    let collection = get_competition_collection(&state);
+    let mut summary = ImportSummary::default();
+    
+    for (index, record) in records.into_iter().enumerate() {
+        let mut competition: Competition = match serde_json::from_value(record) {
+            Ok(competition) => competition,
+            Err(e) => {
+                summary.skipped_records.push(ImportSkipped { index, errors: vec![e.to_string()] });
+                continue;
             }
-            
-            Ok(Json(ApiResponse {
-                success: true,
-                data: Some(competition_with_id),
-                message: Some("Competition created successfully".to_string()),
-            }))
+        };
+        if let Err(errors) = competition.validate() {
+            summary.skipped_records.push(ImportSkipped { index, errors });
+            continue;
+        }
+        competition.normalize_tags();
+        
+        let existing = find_imported_match(&collection, &competition)
+            .await
+            .map_err(|e| AppError::database("Error finding competition to import over", e))?;
+        
+        if let Some(existing) = existing {
+            // Keep the stored id and slug so links and the unique slug index stay intact
+            competition.id = existing.id;
+            competition.slug = existing.slug;
+            competition.created_at = competition.created_at.or(existing.created_at);
+            collection
+                .replace_one(doc! { "_id": existing.id }, &competition)
+                .await
+                .map_err(|e| AppError::database("Error updating imported competition", e))?;
+            summary.updated += 1;
+        } else {
+            if competition.created_at.is_none() {
+                competition.mark_created();
+            }
+            // Reuse the backup's slug unless another competition has taken it since
+            let slug_taken = !competition.slug.is_empty()
+                && collection
+                    .count_documents(doc! { "slug": &competition.slug })
+                    .await
+                    .map_err(|e| AppError::database("Error checking imported slug", e))?
+                    > 0;
+            if competition.slug.is_empty() || slug_taken {
+                assign_slugs(&collection, std::slice::from_mut(&mut competition))
+                    .await
+                    .map_err(|e| AppError::database("Failed to generate competition slug", e))?;
+            }
+            collection
+                .insert_one(&competition)
+                .await
+                .map_err(|e| AppError::database("Error inserting imported competition", e))?;
+            summary.created += 1;
         }
     }
+    summary.skipped = summary.skipped_records.len();
+    // A backup may feature more competitions than this server allows
+    trim_featured(&collection, state.max_featured)
+        .await
+        .map_err(|e| AppError::database("Error limiting featured competitions", e))?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!(
+            "{} created, {} updated, {} skipped",
+            summary.created, summary.updated, summary.skipped
+        )),
+        data: Some(summary),
+    }))
+}
+
+/// Request body for merging two duplicate competitions
+#[derive(Debug, Deserialize)]
+pub struct MergeRequest {
+    pub keep_id: String,
+    pub remove_id: String,
+}
+
+/// Fold `removed` into `kept`: union the comma-joined sources and tags, and fill any empty optional fields
+fn merge_competition_fields(mut kept: Competition, removed: Competition) -> Competition {
+    kept.source = merge_sources(&kept.source, &removed.source);
+    
+    if kept.date_unknown && !removed.date_unknown {
+        kept.date = removed.date;
+        kept.date_unknown = false;
+    }
+    kept.end_date = kept.end_date.or(removed.end_date);
+    kept.description = kept.description.or(removed.description);
+    kept.signup_deadline = kept.signup_deadline.or(removed.signup_deadline);
+    kept.location = kept.location.or(removed.location);
+    kept.registration_link = kept.registration_link.or(removed.registration_link);
+    kept.max_participants = kept.max_participants.or(removed.max_participants);
+    kept.status = kept.status.or(removed.status);
+    kept.tags.extend(removed.tags);
+    kept.normalize_tags();
+    kept.created_at = kept.created_at.or(removed.created_at);
+    kept.external_id = kept.external_id.or(removed.external_id);
+    if !kept.featured && removed.featured {
+        kept.featured = true;
+        kept.featured_at = removed.featured_at;
+    }
+    kept.updated_at = Some(Utc::now());
+    kept
+}
+
+/// Merge a duplicate competition into another, deleting the duplicate
+pub async fn merge_competitions(
+    State(state): State<AppState>,
+    ApiJson(request): ApiJson<MergeRequest>,
+) -> Result<Json<ApiResponse<Competition>>, AppError> {
+    let keep_id = AppError::parse_object_id(&request.keep_id)?;
+    let remove_id = AppError::parse_object_id(&request.remove_id)?;
+    if keep_id == remove_id {
+        return Err(AppError::BadRequest(
+            "keep_id and remove_id must refer to different competitions".to_string(),
+        ));
+    }
+    
+    let collection = get_competition_collection(&state);
+    let kept = collection
+        .find_one(doc! { "_id": keep_id })
+        .await
+        .map_err(|e| AppError::database("Error finding competition to keep", e))?
+        .ok_or(AppError::NotFound)?;
+    let removed = collection
+        .find_one(doc! { "_id": remove_id })
+        .await
+        .map_err(|e| AppError::database("Error finding competition to remove", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    let merged = merge_competition_fields(kept, removed);
+    collection
+        .replace_one(doc! { "_id": keep_id }, &merged)
+        .await
+        .map_err(|e| AppError::database("Error saving merged competition", e))?;
+    collection
+        .delete_one(doc! { "_id": remove_id })
+        .await
+        .map_err(|e| AppError::database("Error deleting merged competition", e))?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(with_computed_status(merged)),
+        message: Some("Competitions merged successfully".to_string()),
+    }))
 }
 
 /// Update an existing competition by ID
 pub async fn update_competition(
     State(state): State<AppState>,
     Path(id): Path<String>,
-    Json(competition): Json<Competition>,
-) -> Result<Json<ApiResponse<Competition>>, StatusCode> {
+    ApiJson(mut competition): ApiJson<Competition>,
+) -> Result<Json<ApiResponse<Competition>>, AppError> {
     let collection = get_competition_collection(&state);
     
     // Validate and convert string ID to ObjectId
-    let object_id = ObjectId::parse_str(&id)
-        .map_err(|e| {
-            tracing::error!("Invalid ObjectId: {}", e);
-            StatusCode::BAD_REQUEST
-        })?;
+    let object_id = AppError::parse_object_id(&id)?;
+    
+    competition.validate().map_err(AppError::Validation)?;
+    competition.normalize_tags();
+    // The creation time, slug and featured state are never client-editable, so leave the stored ones in place
+    competition.created_at = None;
+    competition.slug = String::new();
+    competition.featured = false;
+    competition.featured_at = None;
+    competition.updated_at = Some(Utc::now());
     
     // Prepare update document - exclude the ID from update
     let mut update_doc = mongodb::bson::to_document(&competition)
-        .map_err(|e| {
-            tracing::error!("Error converting competition to document: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+        .map_err(|e| AppError::database("Error converting competition to document", e))?;
     update_doc.remove("_id"); // Remove the ID field from update
+    let mut update = doc! { "$set": update_doc };
+    if !competition.date_unknown {
+        // The client supplied the date, so a stored placeholder flag no longer applies
+        update.insert("$unset", doc! { "date_unknown": "" });
+    }
     
     match collection
-        .update_one(
-            doc! { "_id": object_id },
-            doc! { "$set": update_doc },
-        )
+        .update_one(doc! { "_id": object_id }, update)
         .await
-        .map_err(|e| {
-            tracing::error!("Error updating competition: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
+        .map_err(|e| AppError::database("Error updating competition", e))?
     {
-        mongodb::results::UpdateResult { modified_count: 0, .. } => Err(StatusCode::NOT_FOUND),
+        mongodb::results::UpdateResult { modified_count: 0, .. } => Err(AppError::NotFound),
         _ => {
             // Return the updated competition
             match collection
                 .find_one(doc! { "_id": object_id })
                 .await
-                .map_err(|e| {
-                    tracing::error!("Error finding updated competition: {}", e);
-                    StatusCode::INTERNAL_SERVER_ERROR
-                })?
+                .map_err(|e| AppError::database("Error finding updated competition", e))?
             {
                 Some(updated_competition) => Ok(Json(ApiResponse {
                     success: true,
-                    data: Some(updated_competition),
+                    data: Some(with_computed_status(updated_competition)),
                     message: Some("Competition updated successfully".to_string()),
                 })),
-                None => Err(StatusCode::NOT_FOUND),
+                None => Err(AppError::NotFound),
             }
         }
     }
 }
 
-/// Delete a competition by ID
+/// Archive a competition by ID, keeping the document but hiding it from listings
 pub async fn delete_competition(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> Result<Json<ApiResponse<String>>, StatusCode> {
+) -> Result<Json<ApiResponse<String>>, AppError> {
     let collection = get_competition_collection(&state);
     
     // Validate and convert string ID to ObjectId
-    let object_id = ObjectId::parse_str(&id)
-        .map_err(|e| {
-            tracing::error!("Invalid ObjectId: {}", e);
-            StatusCode::BAD_REQUEST
-        })?;
+    let object_id = AppError::parse_object_id(&id)?;
     
+    // Already archived competitions are treated as missing so the original timestamp is kept.
+    // Archiving also un-features the competition so it stops taking up a featured slot.
     match collection
-        .delete_one(doc! { "_id": object_id })
+        .update_one(
+            doc! { "_id": object_id, "deleted_at": null },
+            doc! {
+                "$set": { "deleted_at": Utc::now().to_rfc3339() },
+                "$unset": { "featured": "", "featured_at": "" },
+            },
+        )
         .await
-        .map_err(|e| {
-            tracing::error!("Error deleting competition: {}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
+        .map_err(|e| AppError::database("Error deleting competition", e))?
     {
-        mongodb::results::DeleteResult { deleted_count: 0, .. } => Err(StatusCode::NOT_FOUND),
+        mongodb::results::UpdateResult { matched_count: 0, .. } => Err(AppError::NotFound),
         _ => Ok(Json(ApiResponse {
             success: true,
             data: Some(id),
@@ -326,12 +1224,322 @@ pub async fn delete_competition(
     }
 }
 
-/// Create the router for competition routes under /competitions path
+/// Query parameters for deleting a source's competitions
+#[derive(Debug, Default, Deserialize)]
+pub struct SourceDeleteQuery {
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// Outcome of removing a source from the competitions collection
+#[derive(Debug, Serialize)]
+pub struct SourceDeleteResult {
+    /// Competitions found only by this source, which were deleted
+    pub deleted: u64,
+    /// Merged competitions that had this source removed from their `source` list
+    pub updated: u64,
+}
+
+/// Drop every occurrence of `source` (case insensitive) from a comma-joined source list
+fn remove_source(sources: &str, source: &str) -> String {
+    sources
+        .split(',')
+        .map(str::trim)
+        .filter(|existing| !existing.is_empty() && !existing.eq_ignore_ascii_case(source))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Permanently delete the competitions found only by one source, e.g. after a misconfigured scraper run.
+///
+/// Competitions merged from several sources are kept, with just this source removed from their list.
+pub async fn delete_competitions_by_source(
+    State(state): State<AppState>,
+    query: Option<Query<SourceDeleteQuery>>,
+) -> Result<Json<ApiResponse<SourceDeleteResult>>, AppError> {
+    let source = query
+        .and_then(|q| q.0.source)
+        .map(|source| source.trim().to_string())
+        .filter(|source| !source.is_empty())
+        .ok_or_else(|| AppError::BadRequest("source is required".to_string()))?;
+    if source.contains(',') {
+        return Err(AppError::BadRequest("source must name a single source".to_string()));
+    }
+    
+    let collection = get_competition_collection(&state);
+    let escaped = regex::escape(&source);
+    
+    let deleted = collection
+        .delete_many(doc! { "source": { "$regex": format!(r"^\s*{escaped}\s*$"), "$options": "i" } })
+        .await
+        .map_err(|e| AppError::database("Error deleting competitions by source", e))?
+        .deleted_count;
+    
+    // Whatever still lists the source was merged from several, so only strip the token
+    let merged_filter = doc! { "source": { "$regex": format!(r"(^|,)\s*{escaped}\s*(,|$)"), "$options": "i" } };
+    let merged = process_competition_cursor(
+        collection
+            .find(merged_filter)
+            .await
+            .map_err(|e| AppError::database("Error finding merged competitions by source", e))?,
+    )
+    .await?;
+    
+    let mut updated = 0;
+    for competition in merged {
+        let Some(id) = competition.id else {
+            continue;
+        };
+        collection
+            .update_one(
+                doc! { "_id": id },
+                doc! { "$set": {
+                    "source": remove_source(&competition.source, &source),
+                    "updated_at": Utc::now().to_rfc3339(),
+                } },
+            )
+            .await
+            .map_err(|e| AppError::database("Error removing source from competition", e))?;
+        updated += 1;
+    }
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!("Deleted {deleted} and updated {updated} competitions from source '{source}'")),
+        data: Some(SourceDeleteResult { deleted, updated }),
+    }))
+}
+
+/// Restore an archived competition by clearing its `deleted_at` timestamp
+pub async fn restore_competition(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<Competition>>, AppError> {
+    let collection = get_competition_collection(&state);
+    
+    let object_id = AppError::parse_object_id(&id)?;
+    
+    let restored = collection
+        .find_one_and_update(
+            doc! { "_id": object_id },
+            doc! { "$unset": { "deleted_at": "" } },
+        )
+        .return_document(mongodb::options::ReturnDocument::After)
+        .await
+        .map_err(|e| AppError::database("Error restoring competition", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(with_computed_status(restored)),
+        message: Some("Competition restored successfully".to_string()),
+    }))
+}
+
+/// Un-feature all but the `max` most recently featured competitions, returning how many were un-featured
+async fn trim_featured(collection: &Collection<Competition>, max: usize) -> Result<u64, mongodb::error::Error> {
+    let featured: Vec<Competition> = collection
+        .find(doc! { "featured": true })
+        .sort(doc! { "featured_at": -1 })
+        .await?
+        .try_collect()
+        .await?;
+    let stale: Vec<ObjectId> = featured.into_iter().skip(max).filter_map(|competition| competition.id).collect();
+    if stale.is_empty() {
+        return Ok(0);
+    }
+    
+    let result = collection
+        .update_many(
+            doc! { "_id": { "$in": stale } },
+            doc! { "$unset": { "featured": "", "featured_at": "" } },
+        )
+        .await?;
+    Ok(result.modified_count)
+}
+
+/// Feature a competition, or un-feature it if it is already featured.
+///
+/// Once more than `MAX_FEATURED` competitions are featured, the ones featured longest ago are un-featured.
+pub async fn toggle_featured(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<Competition>>, AppError> {
+    let collection = get_competition_collection(&state);
+    
+    let object_id = AppError::parse_object_id(&id)?;
+    
+    let existing = collection
+        .find_one(doc! { "_id": object_id })
+        .await
+        .map_err(|e| AppError::database("Error finding competition to feature", e))?
+        .ok_or(AppError::NotFound)?;
+    if !existing.featured && existing.deleted_at.is_some() {
+        return Err(AppError::BadRequest("Archived competitions cannot be featured".to_string()));
+    }
+    
+    let update = if existing.featured {
+        doc! { "$unset": { "featured": "", "featured_at": "" } }
+    } else {
+        doc! { "$set": { "featured": true, "featured_at": Utc::now().to_rfc3339() } }
+    };
+    let updated = collection
+        .find_one_and_update(doc! { "_id": object_id }, update)
+        .return_document(mongodb::options::ReturnDocument::After)
+        .await
+        .map_err(|e| AppError::database("Error updating featured competition", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    let message = if updated.featured {
+        let unfeatured = trim_featured(&collection, state.max_featured)
+            .await
+            .map_err(|e| AppError::database("Error limiting featured competitions", e))?;
+        match unfeatured {
+            0 => "Competition featured".to_string(),
+            n => format!("Competition featured; {n} older featured competitions were un-featured"),
+        }
+    } else {
+        "Competition un-featured".to_string()
+    };
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(with_computed_status(updated)),
+        message: Some(message),
+    }))
+}
+
+/// Get the featured competitions that are not archived, nearest date first
+pub async fn get_featured_competitions(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<Competition>>>, AppError> {
+    let collection = get_competition_collection(&state);
+    
+    let cursor = collection
+        .find(doc! { "featured": true, "deleted_at": null })
+        .sort(doc! { "date": 1 })
+        .await
+        .map_err(|e| AppError::database("Error finding featured competitions", e))?;
+    let competitions: Vec<Competition> = process_competition_cursor(cursor)
+        .await?
+        .into_iter()
+        .map(with_computed_status)
+        .collect();
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!("{} featured competitions", competitions.len())),
+        data: Some(competitions),
+    }))
+}
+
+/// Create the router for competition routes under /competitions path.
+/// Mutating routes require an API key; reads stay public.
 pub fn create_competition_router() -> Router<AppState> {
+    let protected = Router::new()
+        .route("/", post(create_competition).delete(delete_competitions_by_source))
+        .route("/bulk", post(bulk_create_competitions))
+        .route("/import", post(import_competitions))
+        .route("/merge", post(merge_competitions))
+        .route("/:id", put(update_competition))
+        .route("/:id", delete(delete_competition))
+        .route("/:id/restore", post(restore_competition))
+        .route("/:id/feature", patch(toggle_featured))
+        .route_layer(middleware::from_fn(require_api_key));
+    
     Router::new()
         .route("/", get(get_competitions))
+        .route("/count", get(count_competitions))
+        .route("/calendar", get(get_competition_calendar))
+        .route("/featured", get(get_featured_competitions))
+        .route("/slug/:slug", get(get_competition_by_slug))
         .route("/:id", get(get_competition_by_id))
-        .route("/", post(create_competition))
-        .route("/:id", put(update_competition))
-        .route("/:id", delete(delete_competition))
+        .route("/:id/similar", get(get_similar_competitions))
+        .route("/:id/duplicates", get(get_duplicate_competitions))
+        .merge(protected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_filter_matches_the_computed_status() {
+        let query = CompetitionQuery {
+            status: Some("completed".to_string()),
+            ..Default::default()
+        };
+        let filter = build_competition_filter(&query).expect("valid filter");
+        
+        assert!(!filter.contains_key("status"));
+        let comparison = filter.get_document("$expr").and_then(|expr| expr.get_array("$eq")).expect("$expr comparison");
+        assert!(comparison[0].as_document().is_some_and(|expression| expression.contains_key("$switch")));
+        assert_eq!(comparison[1].as_str(), Some("completed"));
+    }
+
+    #[test]
+    fn paginated_response_derives_page_count_and_flags() {
+        let first = PaginatedResponse::new(vec![1, 2, 3], 1, 3, 7);
+        assert_eq!((first.total_pages, first.has_next, first.has_prev), (3, true, false));
+        
+        let last = PaginatedResponse::new(vec![7], 3, 3, 7);
+        assert_eq!((last.total_pages, last.has_next, last.has_prev), (3, false, true));
+        
+        let empty = PaginatedResponse::<u32>::new(Vec::new(), 1, 10, 0);
+        assert_eq!((empty.total_pages, empty.has_next, empty.has_prev), (0, false, false));
+        
+        let unlimited = PaginatedResponse::<u32>::new(Vec::new(), 1, 0, 5);
+        assert_eq!(unlimited.total_pages, 0);
+    }
+
+    #[test]
+    fn pagination_options_skip_earlier_pages() {
+        let options = create_pagination_options(3, 20, doc! { "date": 1 });
+        assert_eq!((options.skip, options.limit), (Some(40), Some(20)));
+        
+        assert_eq!(create_pagination_options(0, 20, doc! {}).skip, Some(0));
+        assert_eq!(
+            create_pagination_options(u32::MAX, 100, doc! {}).skip,
+            Some(u64::from(u32::MAX - 1) * 100)
+        );
+    }
+
+    #[test]
+    fn pagination_config_clamps_the_limit() {
+        let config = PaginationConfig::default();
+        
+        assert_eq!(config.limit(None), 10);
+        assert_eq!(config.limit(Some(25)), 25);
+        assert_eq!(config.limit(Some(1_000)), 100);
+    }
+
+    #[test]
+    fn malformed_date_parameters_are_rejected() {
+        let queries = [
+            CompetitionQuery { date_from: Some("yesterday".to_string()), ..Default::default() },
+            CompetitionQuery { date_to: Some("2024-13-01".to_string()), ..Default::default() },
+            CompetitionQuery { deadline_after: Some("2024-03-01".to_string()), ..Default::default() },
+            CompetitionQuery { deadline_before: Some("soon".to_string()), ..Default::default() },
+        ];
+        
+        for query in queries {
+            match build_competition_filter(&query) {
+                Err(AppError::BadRequest(message)) => assert!(message.contains("expected an RFC3339 date")),
+                other => panic!("expected a bad request, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn empty_date_parameters_are_ignored() {
+        let query = CompetitionQuery {
+            date_from: Some(" ".to_string()),
+            deadline_after: Some(String::new()),
+            ..Default::default()
+        };
+        let filter = build_competition_filter(&query).expect("valid filter");
+        
+        assert!(!filter.contains_key("date"));
+        assert!(!filter.contains_key("$and"));
+    }
 }
@@ -0,0 +1,271 @@
+use std::sync::LazyLock;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use regex::Regex;
+
+/// Hong Kong time, used by the HK university listings
+pub const HKT: FixedOffset = match FixedOffset::east_opt(8 * 3600) {
+    Some(offset) => offset,
+    None => panic!("valid offset"),
+};
+
+/// Japan Standard Time, used by AtCoder
+pub const JST: FixedOffset = match FixedOffset::east_opt(9 * 3600) {
+    Some(offset) => offset,
+    None => panic!("valid offset"),
+};
+
+/// UTC as a fixed offset, for sources whose times carry no zone
+pub const UTC: FixedOffset = match FixedOffset::east_opt(0) {
+    Some(offset) => offset,
+    None => panic!("valid offset"),
+};
+
+/// Formats that carry their own UTC offset, tried after RFC3339 and RFC2822
+const OFFSET_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S%z",
+    "%Y-%m-%dT%H:%M:%S%z",
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M%z",
+];
+
+/// Date and time formats without an offset, read in the caller's time zone
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M",
+    "%d/%m/%Y %H:%M",
+    "%d %B %Y %H:%M",
+    "%d %b %Y %H:%M",
+    "%B %d, %Y %I:%M %p",
+    "%b %d, %Y %I:%M %p",
+    "%d %B %Y %I:%M %p",
+    "%d %b %Y %I:%M %p",
+];
+
+/// Date-only formats, read as midnight in the caller's time zone
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%d/%m/%Y",
+    "%d.%m.%Y",
+    "%d %B %Y",
+    "%d %b %Y",
+    "%d %B, %Y",
+    "%d %b, %Y",
+    "%B %d, %Y",
+    "%b %d, %Y",
+    "%B %d %Y",
+    "%b %d %Y",
+    "%d-%b-%Y",
+    "%d-%B-%Y",
+    "%A, %d %B %Y",
+    "%a, %d %b %Y",
+    "%A, %B %d, %Y",
+    "%a, %b %d, %Y",
+];
+
+/// Parenthesised notes such as "(Tue)" or "(HKT)"
+static NOTES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\([^)]*\)").expect("valid regex"));
+
+/// Ordinal day suffixes such as the "st" in "1st"
+static ORDINALS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(\d{1,2})(st|nd|rd|th)\b").expect("valid regex"));
+
+/// Separators between the two ends of a date range
+const RANGE_SEPARATORS: &[&str] = &[" - ", " – ", " — ", " to ", "–", "—"];
+
+/// Tidy listing text before parsing: drop parenthesised notes like "(Tue)" or "(HKT)",
+/// ordinal suffixes like "1st", and repeated whitespace
+fn clean(text: &str) -> String {
+    let without_notes = NOTES.replace_all(text, " ");
+    let without_ordinals = ORDINALS.replace_all(&without_notes, "$1");
+    without_ordinals.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse a date or date-time, reading times without an explicit offset in `tz`.
+///
+/// Accepts RFC3339, RFC2822 and the formats used by the university and contest sites.
+/// Date-only values are midnight in `tz`. Returns `None` when no format matches.
+pub fn parse_with_tz(text: &str, tz: FixedOffset) -> Option<DateTime<Utc>> {
+    let text = clean(text);
+    if text.is_empty() {
+        return None;
+    }
+
+    let with_offset = DateTime::parse_from_rfc3339(&text)
+        .or_else(|_| DateTime::parse_from_rfc2822(&text))
+        .ok()
+        .or_else(|| {
+            OFFSET_FORMATS
+                .iter()
+                .find_map(|format| DateTime::parse_from_str(&text, format).ok())
+        });
+    if let Some(date) = with_offset {
+        return Some(date.with_timezone(&Utc));
+    }
+
+    let naive = DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(&text, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(&text, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+    naive
+        .and_local_timezone(tz)
+        .single()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+/// Parse a date or date-time, reading times without an explicit offset as UTC
+pub fn parse_date(text: &str) -> Option<DateTime<Utc>> {
+    parse_with_tz(text, UTC)
+}
+
+/// Parse the end of a range that omits the month, e.g. "3, 2024" in "March 1 - 3, 2024",
+/// by borrowing the leading month name from the start
+fn parse_end_with_start_month(start: &str, end: &str, tz: FixedOffset) -> Option<DateTime<Utc>> {
+    let month = start.split_whitespace().next()?;
+    if month.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    parse_with_tz(&format!("{month} {end}"), tz)
+}
+
+/// Parse the start of a range that omits trailing parts shared with the end,
+/// e.g. "1" in "1 - 3 March 2024" or "1 March" in "1 March - 3 April 2024"
+fn parse_start_with_end_suffix(start: &str, end: &str, tz: FixedOffset) -> Option<DateTime<Utc>> {
+    let end_words: Vec<&str> = end.split_whitespace().collect();
+    (1..end_words.len()).rev().find_map(|keep| {
+        let suffix = end_words[end_words.len() - keep..].join(" ");
+        parse_with_tz(&format!("{start} {suffix}"), tz)
+    })
+}
+
+/// Parse a single date or a range such as "1 - 3 March 2024" or "2024-03-01 to 2024-03-03",
+/// returning the start and, for ranges, the end. Times without an offset are read in `tz`.
+pub fn parse_date_range_with_tz(text: &str, tz: FixedOffset) -> Option<(DateTime<Utc>, Option<DateTime<Utc>>)> {
+    let text = clean(text);
+    if let Some(date) = parse_with_tz(&text, tz) {
+        return Some((date, None));
+    }
+
+    RANGE_SEPARATORS.iter().find_map(|separator| {
+        let (start, end) = text.split_once(separator)?;
+        let (start, end) = (start.trim(), end.trim());
+
+        let end_date = parse_with_tz(end, tz).or_else(|| parse_end_with_start_month(start, end, tz))?;
+        let start_date = parse_with_tz(start, tz)
+            .or_else(|| parse_start_with_end_suffix(start, end, tz))
+            .or_else(|| {
+                // "March 1 - 3, 2024": the start lacks the year carried by the end
+                let year = end.rsplit(|c: char| !c.is_ascii_digit()).find(|part| part.len() == 4)?;
+                parse_with_tz(&format!("{start}, {year}"), tz)
+            })?;
+        Some((start_date, Some(end_date)))
+    })
+}
+
+/// Parse a single date or a date range, reading times without an explicit offset as UTC
+pub fn parse_date_range(text: &str) -> Option<(DateTime<Utc>, Option<DateTime<Utc>>)> {
+    parse_date_range_with_tz(text, UTC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        text.parse().expect("valid RFC3339 date")
+    }
+
+    #[test]
+    fn parses_each_accepted_format_as_utc() {
+        let cases = [
+            ("2024-03-01T09:30:00+08:00", "2024-03-01T01:30:00Z"),
+            ("Fri, 01 Mar 2024 09:30:00 +0000", "2024-03-01T09:30:00Z"),
+            ("2024-03-01 09:30:00+0800", "2024-03-01T01:30:00Z"),
+            ("2024-03-01T09:30:00.500+0000", "2024-03-01T09:30:00.500Z"),
+            ("2024-03-01 09:30+0000", "2024-03-01T09:30:00Z"),
+            ("2024-03-01 09:30:00", "2024-03-01T09:30:00Z"),
+            ("2024-03-01T09:30:00", "2024-03-01T09:30:00Z"),
+            ("2024-03-01 09:30", "2024-03-01T09:30:00Z"),
+            ("2024/03/01 09:30", "2024-03-01T09:30:00Z"),
+            ("01/03/2024 09:30", "2024-03-01T09:30:00Z"),
+            ("1 March 2024 09:30", "2024-03-01T09:30:00Z"),
+            ("1 Mar 2024 09:30", "2024-03-01T09:30:00Z"),
+            ("March 1, 2024 9:30 AM", "2024-03-01T09:30:00Z"),
+            ("Mar 1, 2024 9:30 PM", "2024-03-01T21:30:00Z"),
+            ("1 March 2024 9:30 AM", "2024-03-01T09:30:00Z"),
+            ("1 Mar 2024 9:30 PM", "2024-03-01T21:30:00Z"),
+            ("2024-03-01", "2024-03-01T00:00:00Z"),
+            ("2024/03/01", "2024-03-01T00:00:00Z"),
+            ("01/03/2024", "2024-03-01T00:00:00Z"),
+            ("01.03.2024", "2024-03-01T00:00:00Z"),
+            ("1 March 2024", "2024-03-01T00:00:00Z"),
+            ("1 Mar 2024", "2024-03-01T00:00:00Z"),
+            ("1 March, 2024", "2024-03-01T00:00:00Z"),
+            ("1 Mar, 2024", "2024-03-01T00:00:00Z"),
+            ("March 1, 2024", "2024-03-01T00:00:00Z"),
+            ("Mar 1, 2024", "2024-03-01T00:00:00Z"),
+            ("March 1 2024", "2024-03-01T00:00:00Z"),
+            ("Mar 1 2024", "2024-03-01T00:00:00Z"),
+            ("01-Mar-2024", "2024-03-01T00:00:00Z"),
+            ("01-March-2024", "2024-03-01T00:00:00Z"),
+            ("Friday, 1 March 2024", "2024-03-01T00:00:00Z"),
+            ("Fri, 1 Mar 2024", "2024-03-01T00:00:00Z"),
+            ("Friday, March 1, 2024", "2024-03-01T00:00:00Z"),
+            ("Fri, Mar 1, 2024", "2024-03-01T00:00:00Z"),
+            ("1st March 2024 (Fri)", "2024-03-01T00:00:00Z"),
+            ("  1   March   2024  ", "2024-03-01T00:00:00Z"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(parse_date(text), Some(utc(expected)), "parsing '{text}'");
+        }
+    }
+
+    #[test]
+    fn reads_times_without_an_offset_in_the_given_zone() {
+        assert_eq!(parse_with_tz("2024-03-01 09:30", HKT), Some(utc("2024-03-01T01:30:00Z")));
+        assert_eq!(parse_with_tz("1 March 2024", HKT), Some(utc("2024-02-29T16:00:00Z")));
+        assert_eq!(parse_with_tz("2024-03-01 09:30", JST), Some(utc("2024-03-01T00:30:00Z")));
+        // An explicit offset wins over the zone
+        assert_eq!(parse_with_tz("2024-03-01T09:30:00+00:00", JST), Some(utc("2024-03-01T09:30:00Z")));
+    }
+
+    #[test]
+    fn returns_none_for_unparseable_text() {
+        for text in ["", "   ", "(TBC)", "To be announced", "2024-13-01", "next Friday"] {
+            assert_eq!(parse_date(text), None, "parsing '{text}'");
+        }
+        assert_eq!(parse_date_range("To be announced"), None);
+    }
+
+    #[test]
+    fn parses_ranges_with_and_without_an_end() {
+        let cases = [
+            ("2024-03-01", "2024-03-01T00:00:00Z", None),
+            ("2024-03-01 to 2024-03-03", "2024-03-01T00:00:00Z", Some("2024-03-03T00:00:00Z")),
+            ("1 - 3 March 2024", "2024-03-01T00:00:00Z", Some("2024-03-03T00:00:00Z")),
+            ("1 March – 3 April 2024", "2024-03-01T00:00:00Z", Some("2024-04-03T00:00:00Z")),
+            ("March 1 - 3, 2024", "2024-03-01T00:00:00Z", Some("2024-03-03T00:00:00Z")),
+        ];
+
+        for (text, start, end) in cases {
+            assert_eq!(
+                parse_date_range(text),
+                Some((utc(start), end.map(utc))),
+                "parsing '{text}'"
+            );
+        }
+        assert_eq!(
+            parse_date_range_with_tz("1 - 3 March 2024", HKT),
+            Some((utc("2024-02-29T16:00:00Z"), Some(utc("2024-03-02T16:00:00Z"))))
+        );
+    }
+}
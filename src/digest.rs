@@ -0,0 +1,372 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use axum::{
+    extract::{Query, State},
+    response::Json,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use lettre::message::Mailbox;
+use mongodb::{bson::doc, Collection, Database};
+use serde::{Deserialize, Serialize};
+use tokio::{sync::watch, task::JoinHandle};
+
+use crate::{
+    competitions::get_competition_collection, error::AppError, mailer::Mailer, models::Competition,
+    notify::Notifier, AppState, ApiResponse,
+};
+
+/// Days ahead a digest covers unless configured otherwise
+const DEFAULT_DIGEST_DAYS: u32 = 7;
+
+/// Longest window the digest endpoint accepts
+const MAX_DIGEST_DAYS: u32 = 31;
+
+/// Date format used for competition dates in digests
+const DIGEST_DATE_FORMAT: &str = "%a %Y-%m-%d %H:%M UTC";
+
+/// Roundup of the competitions in a date window, in plaintext and HTML
+#[derive(Debug, Clone, Serialize)]
+pub struct Digest {
+    pub from: String,
+    pub to: String,
+    pub count: usize,
+    pub text: String,
+    pub html: String,
+}
+
+/// Escape the HTML special characters in text content and attribute values
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Group competitions by host, with hosts in alphabetical order and each host's competitions by date
+fn group_by_host(competitions: Vec<Competition>) -> BTreeMap<String, Vec<Competition>> {
+    let mut groups: BTreeMap<String, Vec<Competition>> = BTreeMap::new();
+    for competition in competitions {
+        groups.entry(competition.host.clone()).or_default().push(competition);
+    }
+    for group in groups.values_mut() {
+        group.sort_by_key(|competition| competition.date);
+    }
+    groups
+}
+
+/// Heading naming the window a digest covers
+fn digest_title(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    format!(
+        "Competitions from {} to {}",
+        from.format("%Y-%m-%d"),
+        to.format("%Y-%m-%d")
+    )
+}
+
+/// Format the plaintext digest: a heading, then one section per host listing its competitions
+fn format_digest_text(groups: &BTreeMap<String, Vec<Competition>>, from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    let mut text = format!("{}\n", digest_title(from, to));
+    if groups.is_empty() {
+        text.push_str("\nNo competitions are scheduled in this window.\n");
+        return text;
+    }
+
+    for (host, competitions) in groups {
+        text.push_str(&format!("\n{host}\n"));
+        for competition in competitions {
+            text.push_str(&format!("- {} ({})", competition.name, competition.date.format(DIGEST_DATE_FORMAT)));
+            if let Some(deadline) = competition.signup_deadline {
+                text.push_str(&format!(", sign up by {}", deadline.format(DIGEST_DATE_FORMAT)));
+            }
+            if let Some(link) = &competition.registration_link {
+                text.push_str(&format!("\n  {link}"));
+            }
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Format the HTML digest, linking each competition to its registration page when known
+fn format_digest_html(groups: &BTreeMap<String, Vec<Competition>>, from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    let mut html = format!("<h1>{}</h1>\n", escape_html(&digest_title(from, to)));
+    if groups.is_empty() {
+        html.push_str("<p>No competitions are scheduled in this window.</p>\n");
+        return html;
+    }
+
+    for (host, competitions) in groups {
+        html.push_str(&format!("<h2>{}</h2>\n<ul>\n", escape_html(host)));
+        for competition in competitions {
+            let name = match &competition.registration_link {
+                Some(link) => format!("<a href=\"{}\">{}</a>", escape_html(link), escape_html(&competition.name)),
+                None => escape_html(&competition.name),
+            };
+            html.push_str(&format!("<li>{name} ({})", competition.date.format(DIGEST_DATE_FORMAT)));
+            if let Some(deadline) = competition.signup_deadline {
+                html.push_str(&format!(", sign up by {}", deadline.format(DIGEST_DATE_FORMAT)));
+            }
+            html.push_str("</li>\n");
+        }
+        html.push_str("</ul>\n");
+    }
+    html
+}
+
+/// Build the digest for competitions dated between `from` and `to`, grouped by host.
+///
+/// This only formats what it is given, so the competitions must already be limited to the window.
+pub fn build_digest(competitions: Vec<Competition>, from: DateTime<Utc>, to: DateTime<Utc>) -> Digest {
+    let count = competitions.len();
+    let groups = group_by_host(competitions);
+
+    Digest {
+        from: from.to_rfc3339(),
+        to: to.to_rfc3339(),
+        count,
+        text: format_digest_text(&groups, from, to),
+        html: format_digest_html(&groups, from, to),
+    }
+}
+
+/// Competitions that are not archived and are dated from `from` up to, but excluding, `to`
+async fn find_competitions_between(
+    collection: &Collection<Competition>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<Competition>, mongodb::error::Error> {
+    // Dates are stored as RFC3339 strings, so compare against the same representation
+    let filter = doc! {
+        "date": { "$gte": from.to_rfc3339(), "$lt": to.to_rfc3339() },
+        "deleted_at": null,
+    };
+    collection.find(filter).sort(doc! { "date": 1 }).await?.try_collect().await
+}
+
+/// Build the digest of competitions in the `days` days starting now
+async fn digest_for_next_days(collection: &Collection<Competition>, days: u32) -> Result<Digest, mongodb::error::Error> {
+    let from = Utc::now();
+    let to = from + chrono::Duration::days(days.into());
+    let competitions = find_competitions_between(collection, from, to).await?;
+    Ok(build_digest(competitions, from, to))
+}
+
+/// Query parameters for the digest endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct DigestQuery {
+    #[serde(default)]
+    pub days: Option<u32>,
+}
+
+/// Digest of the competitions in the next `days` days (default 7, max 31), grouped by host
+pub async fn get_digest(
+    State(state): State<AppState>,
+    Query(query): Query<DigestQuery>,
+) -> Result<Json<ApiResponse<Digest>>, AppError> {
+    let days = query.days.unwrap_or(DEFAULT_DIGEST_DAYS).clamp(1, MAX_DIGEST_DAYS);
+    let digest = digest_for_next_days(&get_competition_collection(&state), days)
+        .await
+        .map_err(|e| AppError::database("Error building competition digest", e))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!("{} competitions in the next {} days", digest.count, days)),
+        data: Some(digest),
+    }))
+}
+
+/// Create the router for the digest route, nested under /competitions
+pub fn create_digest_router() -> Router<AppState> {
+    Router::new().route("/digest", get(get_digest))
+}
+
+/// Settings for the scheduled digest job
+#[derive(Debug, Clone)]
+pub struct DigestConfig {
+    /// Whether the digest is sent on a schedule at all
+    pub enabled: bool,
+    /// How often the digest is sent
+    pub interval: Duration,
+    /// Days ahead each digest covers
+    pub days: u32,
+    /// Addresses emailed the digest, in addition to the webhook
+    pub recipients: Vec<Mailbox>,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        DigestConfig {
+            enabled: false,
+            interval: Duration::from_secs(7 * 24 * 3600),
+            days: DEFAULT_DIGEST_DAYS,
+            recipients: Vec::new(),
+        }
+    }
+}
+
+impl DigestConfig {
+    /// Build the config from `DIGEST_ENABLED`, `DIGEST_INTERVAL_HOURS`, `DIGEST_DAYS` and the
+    /// comma-separated `DIGEST_EMAIL_TO`, falling back to defaults. Invalid addresses are logged and skipped.
+    pub fn from_env() -> Self {
+        let mut config = DigestConfig::default();
+        let number = |key: &str| std::env::var(key).ok().and_then(|v| v.parse::<u64>().ok());
+
+        config.enabled = std::env::var("DIGEST_ENABLED")
+            .map(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        if let Some(hours) = number("DIGEST_INTERVAL_HOURS").filter(|hours| *hours > 0) {
+            config.interval = Duration::from_secs(hours.saturating_mul(3600));
+        }
+        if let Some(days) = number("DIGEST_DAYS").filter(|days| *days > 0) {
+            config.days = u32::try_from(days).unwrap_or(MAX_DIGEST_DAYS).min(MAX_DIGEST_DAYS);
+        }
+        if let Ok(recipients) = std::env::var("DIGEST_EMAIL_TO") {
+            config.recipients = recipients
+                .split(',')
+                .map(str::trim)
+                .filter(|address| !address.is_empty())
+                .filter_map(|address| match address.parse::<Mailbox>() {
+                    Ok(mailbox) => Some(mailbox),
+                    Err(e) => {
+                        tracing::warn!("Ignoring invalid DIGEST_EMAIL_TO address '{}': {}", address, e);
+                        None
+                    }
+                })
+                .collect();
+        }
+        config
+    }
+}
+
+/// Start the background job that posts the digest to the webhook and emails it to the configured recipients.
+///
+/// The first digest goes out one interval after startup, so restarts do not resend it.
+/// Nothing is started unless `DIGEST_ENABLED` is set and the webhook or mailer can deliver it.
+/// The job stops once `shutdown` changes, finishing any digest already being built.
+pub fn spawn_digest(
+    db: Database,
+    notifier: Notifier,
+    mailer: Mailer,
+    config: DigestConfig,
+    mut shutdown: watch::Receiver<bool>,
+) -> Option<JoinHandle<()>> {
+    if !config.enabled {
+        return None;
+    }
+    let email = mailer.is_enabled() && !config.recipients.is_empty();
+    if !notifier.is_enabled() && !email {
+        tracing::warn!("DIGEST_ENABLED is set but neither WEBHOOK_URL nor SMTP and DIGEST_EMAIL_TO are configured");
+        return None;
+    }
+
+    tracing::info!(
+        "Sending a {}-day competition digest every {} hours",
+        config.days,
+        config.interval.as_secs() / 3600
+    );
+    let collection: Collection<Competition> = db.collection("competitions");
+
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + config.interval, config.interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = shutdown.changed() => break,
+                _ = ticker.tick() => {}
+            }
+            match digest_for_next_days(&collection, config.days).await {
+                Ok(digest) => {
+                    tracing::info!("Sending digest of {} competitions", digest.count);
+                    notifier.notify_digest(&digest);
+                    if email {
+                        mailer.send_digest(&digest, &config.recipients);
+                    }
+                }
+                Err(e) => tracing::error!("Failed to build competition digest: {}", e),
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        text.parse().expect("valid RFC3339 date")
+    }
+
+    fn competition(name: &str, host: &str, date: &str, registration_link: Option<&str>) -> Competition {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "date": date,
+            "host": host,
+            "source": host,
+            "registration_link": registration_link,
+        }))
+        .expect("valid competition")
+    }
+
+    fn sample() -> Vec<Competition> {
+        vec![
+            competition("Quant Cup", "HKUST", "2024-03-05T10:00:00Z", None),
+            competition("Case <Final>", "HKU", "2024-03-04T09:00:00Z", Some("https://example.edu/?a=1&b=2")),
+            competition("Datathon", "HKU", "2024-03-02T09:00:00Z", None),
+        ]
+    }
+
+    #[test]
+    fn text_groups_by_host_in_date_order() {
+        let digest = build_digest(sample(), utc("2024-03-01T00:00:00Z"), utc("2024-03-08T00:00:00Z"));
+
+        assert_eq!(digest.count, 3);
+        assert_eq!(
+            digest.text,
+            "Competitions from 2024-03-01 to 2024-03-08\n\
+             \nHKU\n\
+             - Datathon (Sat 2024-03-02 09:00 UTC)\n\
+             - Case <Final> (Mon 2024-03-04 09:00 UTC)\n  https://example.edu/?a=1&b=2\n\
+             \nHKUST\n\
+             - Quant Cup (Tue 2024-03-05 10:00 UTC)\n"
+        );
+    }
+
+    #[test]
+    fn html_escapes_and_links_names() {
+        let digest = build_digest(sample(), utc("2024-03-01T00:00:00Z"), utc("2024-03-08T00:00:00Z"));
+
+        assert_eq!(
+            digest.html,
+            "<h1>Competitions from 2024-03-01 to 2024-03-08</h1>\n\
+             <h2>HKU</h2>\n<ul>\n\
+             <li>Datathon (Sat 2024-03-02 09:00 UTC)</li>\n\
+             <li><a href=\"https://example.edu/?a=1&amp;b=2\">Case &lt;Final&gt;</a> (Mon 2024-03-04 09:00 UTC)</li>\n\
+             </ul>\n\
+             <h2>HKUST</h2>\n<ul>\n\
+             <li>Quant Cup (Tue 2024-03-05 10:00 UTC)</li>\n\
+             </ul>\n"
+        );
+    }
+
+    #[test]
+    fn empty_digest_says_nothing_is_scheduled() {
+        let digest = build_digest(Vec::new(), utc("2024-03-01T00:00:00Z"), utc("2024-03-08T00:00:00Z"));
+
+        assert_eq!(digest.count, 0);
+        assert_eq!(digest.from, "2024-03-01T00:00:00+00:00");
+        assert_eq!(
+            digest.text,
+            "Competitions from 2024-03-01 to 2024-03-08\n\nNo competitions are scheduled in this window.\n"
+        );
+        assert_eq!(
+            digest.html,
+            "<h1>Competitions from 2024-03-01 to 2024-03-08</h1>\n<p>No competitions are scheduled in this window.</p>\n"
+        );
+    }
+}
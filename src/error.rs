@@ -0,0 +1,139 @@
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use serde_json::json;
+
+use crate::{request_id::current_request_id, ApiResponse};
+
+/// Errors returned by API handlers, rendered as an `ApiResponse` with `success: false`
+#[derive(Debug)]
+pub enum AppError {
+    NotFound,
+    Unauthorized,
+    BadRequest(String),
+    Conflict(String),
+    Database { message: String, correlation_id: String },
+    Validation(Vec<String>),
+    Scraper { message: String, correlation_id: String },
+    RateLimited(std::time::Duration),
+    UnsupportedMediaType,
+}
+
+/// Generate an id that ties a client-facing error to the server log entry with its details
+fn new_correlation_id() -> String {
+    mongodb::bson::oid::ObjectId::new().to_hex()
+}
+
+impl AppError {
+    /// Log an underlying database error and wrap it with a client-facing message
+    pub fn database(context: &str, error: impl std::fmt::Display) -> Self {
+        let correlation_id = new_correlation_id();
+        tracing::error!(correlation_id = %correlation_id, "{}: {}", context, error);
+        AppError::Database {
+            message: context.to_string(),
+            correlation_id,
+        }
+    }
+
+    /// Log a failed scraper run and wrap it with a client-facing message
+    pub fn scraper(context: &str, error: impl std::fmt::Display) -> Self {
+        let correlation_id = new_correlation_id();
+        tracing::error!(correlation_id = %correlation_id, "{}: {}", context, error);
+        AppError::Scraper {
+            message: context.to_string(),
+            correlation_id,
+        }
+    }
+
+    /// Parse a path parameter as an ObjectId, rejecting malformed ids with a 400
+    pub fn parse_object_id(id: &str) -> Result<mongodb::bson::oid::ObjectId, Self> {
+        mongodb::bson::oid::ObjectId::parse_str(id).map_err(|e| {
+            tracing::error!("Invalid ObjectId: {}", e);
+            AppError::BadRequest(format!("Invalid id '{id}': expected a 24 character hex ObjectId"))
+        })
+    }
+
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AppError::BadRequest(_) | AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Database { .. } | AppError::Scraper { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            AppError::NotFound => "Resource not found".to_string(),
+            AppError::Unauthorized => "Missing or invalid x-api-key header".to_string(),
+            AppError::BadRequest(message) | AppError::Conflict(message) => message.clone(),
+            AppError::Database { message, correlation_id }
+            | AppError::Scraper { message, correlation_id } => {
+                format!("{message} (correlation id {correlation_id})")
+            }
+            AppError::Validation(problems) => format!("Validation failed: {}", problems.join("; ")),
+            AppError::RateLimited(wait) => format!(
+                "Scraper ran too recently; retry in {} seconds",
+                retry_after_secs(*wait)
+            ),
+            AppError::UnsupportedMediaType => {
+                "Expected a JSON request body sent with the header `Content-Type: application/json`".to_string()
+            }
+        }
+    }
+}
+
+impl From<JsonRejection> for AppError {
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            JsonRejection::MissingJsonContentType(_) => AppError::UnsupportedMediaType,
+            // Syntax and shape errors already name the problem and where it is, e.g. a missing field
+            rejection => AppError::BadRequest(rejection.body_text()),
+        }
+    }
+}
+
+/// JSON body extractor that rejects with an `AppError`, so a missing content type gets a `415` and
+/// malformed JSON a `400`, both in the usual `ApiResponse` shape
+#[derive(FromRequest)]
+#[from_request(via(Json), rejection(AppError))]
+pub struct ApiJson<T>(pub T);
+
+/// Whole seconds to wait, rounded up so clients never retry too early
+fn retry_after_secs(wait: std::time::Duration) -> u64 {
+    wait.as_secs() + u64::from(wait.subsec_nanos() > 0)
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        // Validation errors list each problem in `data`; server errors carry the correlation id
+        let data = match &self {
+            AppError::Validation(problems) => Some(json!(problems)),
+            AppError::Database { correlation_id, .. } | AppError::Scraper { correlation_id, .. } => {
+                Some(json!({ "correlation_id": correlation_id }))
+            }
+            _ => None,
+        };
+        let mut body = json!(ApiResponse {
+            success: false,
+            data,
+            message: Some(self.message()),
+        });
+        if let Some(request_id) = current_request_id() {
+            body["request_id"] = json!(request_id);
+        }
+
+        let mut response = (self.status_code(), Json(body)).into_response();
+        if let AppError::RateLimited(wait) = self {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, retry_after_secs(wait).into());
+        }
+        response
+    }
+}
@@ -0,0 +1,398 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::header,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use mongodb::{bson::doc, Collection};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    auth::require_api_key,
+    competitions::{
+        find_all_competitions, find_competitions_cursor, get_competition_collection,
+        with_computed_status, CompetitionQuery,
+    },
+    error::AppError,
+    models::{Competition, CompetitionResult, Participant},
+    AppState,
+};
+
+/// Error type of the streamed backup body, covering both cursor and serialization failures
+type BackupError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Maximum number of items included in the RSS feed
+const FEED_ITEM_LIMIT: usize = 50;
+
+/// Column headers for the CSV export
+const CSV_HEADER: &str = "name,date,host,source,location,status,signup_deadline,registration_link";
+
+/// Escape text values per RFC 5545 (backslash, semicolon, comma and newlines)
+fn escape_ical_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line to at most 75 octets, continuing with a leading space
+fn fold_ical_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_len = 0;
+    
+    for c in line.chars() {
+        if line_len + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_len = 1;
+        }
+        folded.push(c);
+        line_len += c.len_utf8();
+    }
+    
+    folded
+}
+
+/// Format a timestamp in the iCalendar UTC form, e.g. 20231015T100000Z
+fn format_ical_datetime(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Build a VEVENT block for a competition
+fn competition_to_vevent(competition: &Competition, stamp: &DateTime<Utc>) -> Vec<String> {
+    // Stable UID derived from the ObjectId so calendar clients update rather than duplicate events
+    let uid = match competition.id {
+        Some(id) => format!("{}@competitions", id.to_hex()),
+        None => format!("{}-{}@competitions", format_ical_datetime(&competition.date), escape_ical_text(&competition.name)),
+    };
+    
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{uid}"),
+        format!("DTSTAMP:{}", format_ical_datetime(stamp)),
+        format!("DTSTART:{}", format_ical_datetime(&competition.date)),
+        format!("SUMMARY:{}", escape_ical_text(&competition.display_name())),
+    ];
+    if let Some(description) = &competition.description {
+        lines.push(format!("DESCRIPTION:{}", escape_ical_text(description)));
+    }
+    if let Some(location) = &competition.location {
+        lines.push(format!("LOCATION:{}", escape_ical_text(location)));
+    }
+    if let Some(link) = &competition.registration_link {
+        lines.push(format!("URL:{link}"));
+    }
+    lines.push("END:VEVENT".to_string());
+    
+    lines
+}
+
+/// Build a complete VCALENDAR document for the given competitions
+fn build_icalendar(competitions: &[Competition]) -> String {
+    let stamp = Utc::now();
+    
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Competition Scraper API//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for competition in competitions {
+        lines.extend(competition_to_vevent(competition, &stamp));
+    }
+    lines.push("END:VCALENDAR".to_string());
+    
+    lines
+        .iter()
+        .map(|line| fold_ical_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// Export competitions matching the list filters as an iCalendar feed
+pub async fn get_competitions_ical(
+    State(state): State<AppState>,
+    query: Option<Query<CompetitionQuery>>,
+) -> Result<impl IntoResponse, AppError> {
+    let query = query.map(|q| q.0).unwrap_or_default();
+    let competitions = find_all_competitions(&state, &query).await?;
+    
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        build_icalendar(&competitions),
+    ))
+}
+
+/// Export a single competition as an iCalendar event
+pub async fn get_competition_ical(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    // Validate and convert string ID to ObjectId
+    let object_id = AppError::parse_object_id(&id)?;
+    
+    let competition = get_competition_collection(&state)
+        .find_one(doc! { "_id": object_id })
+        .await
+        .map_err(|e| AppError::database("Error finding competition by ID", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        build_icalendar(&[competition]),
+    ))
+}
+
+/// Quote a CSV field when it contains a comma, quote or line break, doubling embedded quotes
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Format a competition as a single CSV row, including the trailing line break
+fn competition_to_csv_row(competition: &Competition) -> String {
+    let fields = [
+        competition.name.clone(),
+        competition.date.to_rfc3339(),
+        competition.host.clone(),
+        competition.source.clone(),
+        competition.location.clone().unwrap_or_default(),
+        competition.status.clone().unwrap_or_default(),
+        competition.signup_deadline.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        competition.registration_link.clone().unwrap_or_default(),
+    ];
+    
+    fields
+        .iter()
+        .map(|field| escape_csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+        + "\r\n"
+}
+
+/// Export competitions matching the list filters as CSV, streamed row by row from the cursor
+pub async fn get_competitions_csv(
+    State(state): State<AppState>,
+    query: Option<Query<CompetitionQuery>>,
+) -> Result<impl IntoResponse, AppError> {
+    let query = query.map(|q| q.0).unwrap_or_default();
+    let cursor = find_competitions_cursor(&state, &query).await?;
+    
+    let header_row = stream::once(async { Ok::<_, mongodb::error::Error>(format!("{CSV_HEADER}\r\n")) });
+    let rows = cursor.map_ok(|competition| competition_to_csv_row(&with_computed_status(competition)));
+    
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"competitions.csv\""),
+        ],
+        Body::from_stream(header_row.chain(rows)),
+    ))
+}
+
+/// Query parameters accepted by the RSS feed
+#[derive(Debug, Default, Deserialize)]
+pub struct FeedQuery {
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub host: Option<String>,
+}
+
+/// Escape the XML special characters in text content
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Build an RSS item element for a competition
+fn competition_to_rss_item(competition: &Competition) -> String {
+    let mut item = format!("<item><title>{}</title>", escape_xml(&competition.display_name()));
+    if let Some(link) = &competition.registration_link {
+        item.push_str(&format!("<link>{}</link>", escape_xml(link)));
+    }
+    if let Some(description) = &competition.description {
+        item.push_str(&format!("<description>{}</description>", escape_xml(description)));
+    }
+    item.push_str(&format!("<pubDate>{}</pubDate>", competition.date.to_rfc2822()));
+    if let Some(id) = competition.id {
+        item.push_str(&format!("<guid isPermaLink=\"false\">{}@competitions</guid>", id.to_hex()));
+    }
+    item.push_str("</item>");
+    
+    item
+}
+
+/// Build a complete RSS 2.0 document for the given competitions
+fn build_rss_feed(competitions: &[Competition]) -> String {
+    let mut feed = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\"><channel>");
+    feed.push_str("<title>Upcoming Competitions</title>");
+    feed.push_str("<link>/competitions</link>");
+    feed.push_str("<description>Upcoming competitions collected by the Competition Scraper API</description>");
+    feed.push_str(&format!("<lastBuildDate>{}</lastBuildDate>", Utc::now().to_rfc2822()));
+    for competition in competitions {
+        feed.push_str(&competition_to_rss_item(competition));
+    }
+    feed.push_str("</channel></rss>\n");
+    
+    feed
+}
+
+/// Serve the soonest upcoming competitions as an RSS feed, optionally filtered by source and host
+pub async fn get_competitions_feed(
+    State(state): State<AppState>,
+    query: Option<Query<FeedQuery>>,
+) -> Result<impl IntoResponse, AppError> {
+    let FeedQuery { source, host } = query.map(|q| q.0).unwrap_or_default();
+    let competition_query = CompetitionQuery {
+        source,
+        host,
+        upcoming: Some(true),
+        ..Default::default()
+    };
+    
+    let competitions: Vec<Competition> = find_competitions_cursor(&state, &competition_query)
+        .await?
+        .take(FEED_ITEM_LIMIT)
+        .map_ok(with_computed_status)
+        .try_collect()
+        .await
+        .map_err(|e| AppError::database("Error collecting competitions for feed", e))?;
+    
+    Ok((
+        [(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")],
+        build_rss_feed(&competitions),
+    ))
+}
+
+/// Query parameters accepted by the JSON backup export
+#[derive(Debug, Default, Deserialize)]
+pub struct BackupQuery {
+    /// Wrap competitions, participants and results in one object instead of exporting competitions alone
+    #[serde(default)]
+    pub full: Option<bool>,
+}
+
+/// Stream every document of a collection as the elements of a JSON array, comma-separated
+/// and without the surrounding brackets, reading from the cursor as the body is sent
+async fn json_array_elements<T>(
+    collection: Collection<T>,
+) -> Result<impl Stream<Item = Result<String, BackupError>>, mongodb::error::Error>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + Unpin + 'static,
+{
+    let cursor = collection.find(doc! {}).sort(doc! { "_id": 1 }).await?;
+    Ok(cursor.enumerate().map(|(index, document)| {
+        let json = serde_json::to_string(&document?)?;
+        Ok(if index == 0 { json } else { format!(",{json}") })
+    }))
+}
+
+/// A streamed piece of the backup body that cannot fail
+fn backup_chunk(text: &'static str) -> impl Stream<Item = Result<String, BackupError>> {
+    stream::once(async move { Ok(text.to_string()) })
+}
+
+/// Export every competition, including archived ones, as a JSON array for backup. With `full=true`
+/// the body is an object holding `competitions`, `participants` and `results` arrays instead.
+///
+/// Documents are streamed from the cursors rather than buffered, so large collections export in constant memory.
+pub async fn get_competitions_backup(
+    State(state): State<AppState>,
+    query: Option<Query<BackupQuery>>,
+) -> Result<impl IntoResponse, AppError> {
+    let full = query.and_then(|q| q.full).unwrap_or(false);
+    let competitions = json_array_elements(get_competition_collection(&state))
+        .await
+        .map_err(|e| AppError::database("Error exporting competitions", e))?;
+    
+    let body = if full {
+        let participants = json_array_elements(state.db.collection::<Participant>("participants"))
+            .await
+            .map_err(|e| AppError::database("Error exporting participants", e))?;
+        let results = json_array_elements(state.db.collection::<CompetitionResult>("results"))
+            .await
+            .map_err(|e| AppError::database("Error exporting results", e))?;
+        
+        Body::from_stream(
+            backup_chunk("{\"competitions\":[")
+                .chain(competitions)
+                .chain(backup_chunk("],\"participants\":["))
+                .chain(participants)
+                .chain(backup_chunk("],\"results\":["))
+                .chain(results)
+                .chain(backup_chunk("]}\n")),
+        )
+    } else {
+        Body::from_stream(backup_chunk("[").chain(competitions).chain(backup_chunk("]\n")))
+    };
+    
+    let filename = format!("competitions-backup-{}.json", Utc::now().format("%Y%m%dT%H%M%SZ"));
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{filename}\"")),
+        ],
+        body,
+    ))
+}
+
+/// Create the router for export routes, nested under /competitions
+pub fn create_export_router() -> Router<AppState> {
+    let protected = Router::new()
+        .route("/export.json", get(get_competitions_backup))
+        .route_layer(middleware::from_fn(require_api_key));
+    
+    Router::new()
+        .route("/export.csv", get(get_competitions_csv))
+        .route("/feed.xml", get(get_competitions_feed))
+        .route("/ical", get(get_competitions_ical))
+        .route("/:id/ical", get(get_competition_ical))
+        .merge(protected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ical_text_escapes_special_characters() {
+        assert_eq!(
+            escape_ical_text("a\\b; c, d\r\ne\nf"),
+            "a\\\\b\\; c\\, d\\ne\\nf"
+        );
+    }
+
+    #[test]
+    fn ical_lines_fold_at_75_octets() {
+        let line = "x".repeat(80);
+        let folded = fold_ical_line(&line);
+        
+        assert_eq!(folded, format!("{}\r\n {}", "x".repeat(75), "x".repeat(5)));
+        assert_eq!(fold_ical_line("short"), "short");
+    }
+
+    #[test]
+    fn ical_lines_fold_between_multi_byte_characters() {
+        // 74 ASCII octets leave one free, too few for the 3-octet character that follows
+        let line = format!("{}香港", "x".repeat(74));
+        let folded = fold_ical_line(&line);
+        
+        assert_eq!(folded, format!("{}\r\n 香港", "x".repeat(74)));
+        for segment in folded.split("\r\n") {
+            assert!(segment.len() <= 75);
+        }
+    }
+}
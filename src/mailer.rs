@@ -0,0 +1,203 @@
+use lettre::{
+    message::{Mailbox, MultiPart}, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::{
+    digest::Digest,
+    models::{Competition, Participant},
+};
+
+/// Port using implicit TLS; any other port upgrades the connection with STARTTLS
+const SMTPS_PORT: u16 = 465;
+
+/// Build the plain-text body of a registration confirmation email
+pub fn registration_body(participant: &Participant, competition: &Competition) -> String {
+    let mut body = format!(
+        "Hi {},\n\nYou are registered for {}.\n\nDate: {}\n",
+        participant.name,
+        competition.name,
+        competition.date.format("%Y-%m-%d %H:%M UTC"),
+    );
+    if let Some(link) = &competition.registration_link {
+        body.push_str(&format!("Details: {link}\n"));
+    }
+    body.push_str("\nGood luck!\n");
+    body
+}
+
+/// Sends registration confirmation emails over SMTP
+#[derive(Clone)]
+pub struct Mailer {
+    transport: Option<(AsyncSmtpTransport<Tokio1Executor>, Mailbox)>,
+}
+
+impl Mailer {
+    /// A mailer that never sends anything
+    pub fn disabled() -> Self {
+        Mailer { transport: None }
+    }
+
+    /// Build a mailer from `SMTP_HOST`, `SMTP_PORT`, `SMTP_USERNAME`, `SMTP_PASSWORD` and `SMTP_FROM`.
+    ///
+    /// Without `SMTP_HOST` and `SMTP_FROM` the mailer is disabled. Invalid settings are logged
+    /// and also disable it rather than stopping the server.
+    pub fn from_env() -> Self {
+        let var = |key: &str| std::env::var(key).ok().filter(|value| !value.trim().is_empty());
+        let (Some(host), Some(from)) = (var("SMTP_HOST"), var("SMTP_FROM")) else {
+            return Self::disabled();
+        };
+
+        let from: Mailbox = match from.parse() {
+            Ok(from) => from,
+            Err(e) => {
+                tracing::error!("Invalid SMTP_FROM '{}', emails are disabled: {}", from, e);
+                return Self::disabled();
+            }
+        };
+        let port = var("SMTP_PORT")
+            .and_then(|port| port.parse::<u16>().ok())
+            .unwrap_or(587);
+
+        let builder = if port == SMTPS_PORT {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&host)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+        };
+        let mut builder = match builder {
+            Ok(builder) => builder.port(port),
+            Err(e) => {
+                tracing::error!("Invalid SMTP_HOST '{}', emails are disabled: {}", host, e);
+                return Self::disabled();
+            }
+        };
+        if let (Some(username), Some(password)) = (var("SMTP_USERNAME"), var("SMTP_PASSWORD")) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        tracing::info!("Sending registration emails through {}:{}", host, port);
+        Mailer {
+            transport: Some((builder.build(), from)),
+        }
+    }
+
+    /// Whether an SMTP server is configured
+    pub fn is_enabled(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    /// Email the participant a confirmation in a background task so registration never waits on SMTP.
+    /// Failures are logged and otherwise ignored.
+    pub fn send_registration_confirmation(&self, participant: &Participant, competition: &Competition) {
+        let Some((transport, from)) = self.transport.clone() else {
+            return;
+        };
+
+        let to: Mailbox = match participant.email.parse() {
+            Ok(to) => to,
+            Err(e) => {
+                tracing::warn!("Not emailing invalid address {}: {}", participant.email, e);
+                return;
+            }
+        };
+        let message = Message::builder()
+            .from(from)
+            .to(to)
+            .subject(format!("Registration confirmed: {}", competition.name))
+            .body(registration_body(participant, competition));
+        let message = match message {
+            Ok(message) => message,
+            Err(e) => {
+                tracing::error!("Failed to build registration email: {}", e);
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = transport.send(message).await {
+                tracing::error!("Failed to send registration email: {}", e);
+            }
+        });
+    }
+
+    /// Email the digest, with plaintext and HTML alternatives, to each recipient separately
+    /// from a background task. Failures are logged and otherwise ignored.
+    pub fn send_digest(&self, digest: &Digest, recipients: &[Mailbox]) {
+        let Some((transport, from)) = self.transport.clone() else {
+            return;
+        };
+        let subject = digest.text.lines().next().unwrap_or("Upcoming competitions").to_string();
+
+        let messages: Vec<Message> = recipients
+            .iter()
+            .filter_map(|to| {
+                Message::builder()
+                    .from(from.clone())
+                    .to(to.clone())
+                    .subject(subject.clone())
+                    .multipart(MultiPart::alternative_plain_html(digest.text.clone(), digest.html.clone()))
+                    .map_err(|e| tracing::error!("Failed to build digest email for {}: {}", to, e))
+                    .ok()
+            })
+            .collect();
+
+        tokio::spawn(async move {
+            for message in messages {
+                if let Err(e) = transport.send(message).await {
+                    tracing::error!("Failed to send digest email: {}", e);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use mongodb::bson::oid::ObjectId;
+
+    use super::*;
+
+    fn participant() -> Participant {
+        Participant {
+            id: None,
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            competition_id: ObjectId::new(),
+            registration_date: Utc::now(),
+            status: None,
+        }
+    }
+
+    fn competition(registration_link: Option<&str>) -> Competition {
+        serde_json::from_value(serde_json::json!({
+            "name": "Global Case Competition",
+            "date": Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap().to_rfc3339(),
+            "host": "HKU",
+            "source": "HKU",
+            "registration_link": registration_link,
+        }))
+        .expect("valid competition")
+    }
+
+    #[test]
+    fn registration_body_includes_details_link() {
+        let body = registration_body(&participant(), &competition(Some("https://example.edu/apply")));
+
+        assert_eq!(
+            body,
+            "Hi Ada,\n\nYou are registered for Global Case Competition.\n\nDate: 2024-03-01 09:30 UTC\n\
+             Details: https://example.edu/apply\n\nGood luck!\n"
+        );
+    }
+
+    #[test]
+    fn registration_body_without_link_omits_details() {
+        let body = registration_body(&participant(), &competition(None));
+
+        assert_eq!(
+            body,
+            "Hi Ada,\n\nYou are registered for Global Case Competition.\n\nDate: 2024-03-01 09:30 UTC\n\nGood luck!\n"
+        );
+    }
+}
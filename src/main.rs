@@ -1,21 +1,51 @@
 use axum::{
+    extract::State,
+    http::StatusCode,
     response::Json,
     routing::get,
     Router,
 };
 use mongodb::{options::ClientOptions, Client, Database};
 use serde::Serialize;
-use std::net::SocketAddr;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tokio::{sync::watch, task::JoinHandle};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod auth;
 mod models;
 mod competitions;
+mod dateparse;
+mod digest;
+mod error;
+mod exports;
+mod mailer;
+mod metrics;
+mod notify;
+mod participants;
+mod reminders;
+mod request_id;
+mod results;
 mod scrapers;
+mod stats;
 
-// Application state to hold the database connection
+use competitions::PaginationConfig;
+use digest::{spawn_digest, DigestConfig};
+use mailer::Mailer;
+use metrics::Metrics;
+use notify::Notifier;
+use reminders::{spawn_deadline_reminders, ReminderConfig};
+use scrapers::{load_configurable_scrapers, HttpClientConfig, MatchConfig, ScraperManager, ScraperReachability};
+
+// Application state to hold the database connection and shared scrapers
 #[derive(Clone)]
 pub struct AppState {
     db: Database,
+    scrapers: Arc<ScraperManager>,
+    notifier: Notifier,
+    pagination: PaginationConfig,
+    max_featured: usize,
+    metrics: Arc<Metrics>,
+    mailer: Mailer,
 }
 
 // Response for API endpoints
@@ -26,8 +56,10 @@ pub struct ApiResponse<T> {
     message: Option<String>,
 }
 
+/// How long the readiness probe waits for MongoDB to answer a ping
+const HEALTH_PING_TIMEOUT: Duration = Duration::from_secs(3);
 
-
+// Liveness probe: answers as long as the process is serving requests
 async fn health_handler() -> Json<ApiResponse<String>> {
     Json(ApiResponse {
         success: true,
@@ -36,6 +68,115 @@ async fn health_handler() -> Json<ApiResponse<String>> {
     })
 }
 
+// Readiness probe: also checks that MongoDB answers a ping
+async fn readiness_handler(State(state): State<AppState>) -> (StatusCode, Json<ApiResponse<String>>) {
+    let ping = tokio::time::timeout(
+        HEALTH_PING_TIMEOUT,
+        state.db.run_command(mongodb::bson::doc! { "ping": 1 }),
+    )
+    .await;
+
+    match ping {
+        Ok(Ok(_)) => (
+            StatusCode::OK,
+            Json(ApiResponse {
+                success: true,
+                data: Some("Server is running".to_string()),
+                message: Some("Database reachable".to_string()),
+            }),
+        ),
+        Ok(Err(e)) => {
+            tracing::error!("Health check database ping failed: {}", e);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ApiResponse {
+                    success: false,
+                    data: None,
+                    message: Some("Database unreachable".to_string()),
+                }),
+            )
+        }
+        Err(_) => {
+            tracing::error!("Health check database ping timed out");
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ApiResponse {
+                    success: false,
+                    data: None,
+                    message: Some("Database ping timed out".to_string()),
+                }),
+            )
+        }
+    }
+}
+
+// Reports whether each scraper's source site answers, without running any scrapers
+async fn scraper_health_handler(State(state): State<AppState>) -> Json<ApiResponse<Vec<ScraperReachability>>> {
+    let results = state.scrapers.check_reachability().await;
+    let reachable = results.iter().filter(|result| result.reachable).count();
+
+    Json(ApiResponse {
+        success: true,
+        message: Some(format!("{} of {} scraper sources reachable", reachable, results.len())),
+        data: Some(results),
+    })
+}
+
+/// Create every collection index the API relies on. Existing indexes with the same
+/// specification are left untouched, so this is safe to run on every startup.
+async fn ensure_indexes(db: &Database) -> Result<(), mongodb::error::Error> {
+    let collections = [
+        ("competitions", competitions::competition_indexes()),
+        ("participants", participants::participant_indexes()),
+        ("scraper_runs", scrapers::scraper_run_indexes()),
+    ];
+
+    for (collection, indexes) in collections {
+        for index in indexes {
+            let result = db
+                .collection::<mongodb::bson::Document>(collection)
+                .create_index(index)
+                .await?;
+            tracing::info!("Ensured index {} on {}", result.index_name, collection);
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve once Ctrl-C or SIGTERM is received, letting the server drain in-flight requests
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("Shutting down, waiting for outstanding requests to finish");
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing
@@ -50,9 +191,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Set up MongoDB connection
     let mongo_uri = std::env::var("MONGODB_URI").unwrap_or_else(|_| "mongodb://localhost:27017".to_string());
-    let client_options = ClientOptions::parse(mongo_uri).await?;
+    let mut client_options = ClientOptions::parse(&mongo_uri)
+        .await
+        .map_err(|e| format!("Invalid MONGODB_URI: {e}"))?;
+    if let Some(max_pool_size) = std::env::var("MONGODB_MAX_POOL_SIZE")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+    {
+        client_options.max_pool_size = Some(max_pool_size);
+    }
+    if let Some(connect_timeout_ms) = std::env::var("MONGODB_CONNECT_TIMEOUT_MS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        client_options.connect_timeout = Some(Duration::from_millis(connect_timeout_ms));
+    }
     let client = Client::with_options(client_options)?;
-    let db = client.database("comp_db");
+    let db_name = std::env::var("MONGODB_DB").unwrap_or_else(|_| "comp_db".to_string());
+    let db = client.database(&db_name);
+    tracing::info!("Using MongoDB database {}", db_name);
 
     // Test the connection
     match client.list_database_names().await {
@@ -60,15 +217,128 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => tracing::error!("Failed to connect to MongoDB: {}", e),
     }
 
+    // Create indexes used by list filters, search and participant registration
+    if let Err(e) = ensure_indexes(&db).await {
+        tracing::error!("Failed to create indexes: {}", e);
+    }
+    match scrapers::move_end_dates_out_of_deadlines(&db).await {
+        Ok(0) => {}
+        Ok(moved) => tracing::info!("Moved {} scraped end dates out of signup_deadline", moved),
+        Err(e) => tracing::error!("Failed to move scraped end dates out of signup_deadline: {}", e),
+    }
+
+    // Set up the scraper manager once so scrapers can be registered before serving
+    let http_config = HttpClientConfig::from_env();
+    if http_config.accept_invalid_certs {
+        tracing::warn!("SCRAPER_ACCEPT_INVALID_CERTS is set, so scraper and webhook requests skip certificate validation");
+    } else if !http_config.insecure_scrapers.is_empty() {
+        let mut names: Vec<&String> = http_config.insecure_scrapers.iter().collect();
+        names.sort();
+        tracing::warn!("Skipping certificate validation for scrapers: {:?}", names);
+    }
+    match http_config.proxy_summary() {
+        Some(proxy) => tracing::info!("Scrapers connect through proxy {}", proxy),
+        None => tracing::info!("Scrapers connect directly, no proxy configured"),
+    }
+    let mut scraper_manager = ScraperManager::with_http_config(http_config)?;
+    let max_retries = std::env::var("SCRAPER_MAX_RETRIES")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(3);
+    let retry_base_delay_ms = std::env::var("SCRAPER_RETRY_BASE_MS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(500);
+    scraper_manager.set_retry_policy(max_retries, Duration::from_millis(retry_base_delay_ms));
+    let cooldown_secs = std::env::var("SCRAPER_COOLDOWN_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(60);
+    scraper_manager.set_cooldown(Duration::from_secs(cooldown_secs));
+    let concurrency = std::env::var("SCRAPER_CONCURRENCY")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(3);
+    scraper_manager.set_concurrency(concurrency);
+    let jitter_ms = std::env::var("SCRAPER_JITTER_MS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(1000);
+    scraper_manager.set_jitter(Duration::from_millis(jitter_ms));
+    scraper_manager.set_match_config(MatchConfig::from_env());
+    let scraper_config_path = std::env::var("SCRAPERS_CONFIG").unwrap_or_else(|_| "scrapers.json".to_string());
+    for scraper in load_configurable_scrapers(&scraper_config_path) {
+        scraper_manager.register_scraper(Box::new(scraper));
+    }
+    // Deployments that only care about some sources can skip registering the rest
+    if let Some(allowed) = std::env::var("ENABLED_SCRAPERS")
+        .ok()
+        .map(|v| {
+            v.split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|names| !names.is_empty())
+    {
+        let unknown = scraper_manager.retain_scrapers(&allowed);
+        if !unknown.is_empty() {
+            tracing::warn!("ENABLED_SCRAPERS names unknown scrapers: {:?}", unknown);
+        }
+    }
+    let mut registered = scraper_manager.get_scraper_names();
+    registered.sort();
+    tracing::info!("Registered scrapers: {:?}", registered);
+    if let Err(e) = scraper_manager.load_settings(&db).await {
+        tracing::error!("Failed to load scraper settings: {}", e);
+    }
+    let mut enabled = scraper_manager.get_enabled_scraper_names();
+    enabled.sort();
+    tracing::info!("Enabled scrapers: {:?}", enabled);
+
     // Create application state
-    let app_state = AppState { db };
+    let notifier = Notifier::from_env(scraper_manager.client());
+    // Background jobs watch this channel so shutdown can stop them before the client closes
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mailer = Mailer::from_env();
+    let background_jobs: Vec<JoinHandle<()>> = [
+        spawn_deadline_reminders(db.clone(), notifier.clone(), ReminderConfig::from_env(), shutdown_rx.clone()),
+        spawn_digest(db.clone(), notifier.clone(), mailer.clone(), DigestConfig::from_env(), shutdown_rx),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let app_state = AppState {
+        db,
+        scrapers: Arc::new(scraper_manager),
+        notifier,
+        pagination: PaginationConfig::from_env(),
+        max_featured: competitions::max_featured_from_env(),
+        metrics: Arc::new(Metrics::default()),
+        mailer,
+    };
+    let metrics = app_state.metrics.clone();
 
     // Build our application with some routes
     let app = Router::new()
         .route("/", get(health_handler))
-        .route("/health", get(health_handler))
-        .nest("/competitions", competitions::create_competition_router())
+        .route("/health", get(readiness_handler))
+        .route("/health/scrapers", get(scraper_health_handler))
+        .route("/metrics", get(metrics::metrics_handler))
+        .nest(
+            "/competitions",
+            competitions::create_competition_router()
+                .merge(participants::create_participant_router())
+                .merge(results::create_result_router())
+                .merge(exports::create_export_router())
+                .merge(stats::create_stats_router())
+                .merge(digest::create_digest_router()),
+        )
         .nest("/scrapers", scrapers::create_scraper_router())
+        // Compresses JSON, CSV, iCal and feed responses when the client sends Accept-Encoding
+        .layer(tower_http::compression::CompressionLayer::new())
+        .layer(axum::middleware::from_fn_with_state(metrics, metrics::track_requests))
+        .layer(axum::middleware::from_fn(request_id::track_request_id))
         .with_state(app_state);
 
     // Run the server
@@ -76,7 +346,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Server running on http://{}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            let _ = shutdown_tx.send(true);
+        })
+        .await
+        .unwrap();
+
+    for job in background_jobs {
+        if let Err(e) = job.await {
+            tracing::error!("Background job failed: {}", e);
+        }
+    }
+
+    // All in-flight requests and background jobs have finished, so no sessions are checked out of the pool
+    tracing::info!("Server stopped, closing MongoDB connections");
+    client.shutdown().await;
 
     Ok(())
 }
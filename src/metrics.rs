@@ -0,0 +1,166 @@
+use std::{collections::BTreeMap, fmt::Write, sync::Arc, sync::Mutex};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::AppState;
+
+/// Counter values, keyed by their label values
+#[derive(Debug, Default)]
+struct Counters {
+    competitions_inserted: BTreeMap<String, u64>,
+    competitions_merged: BTreeMap<String, u64>,
+    scraper_errors: BTreeMap<String, u64>,
+    http_requests: BTreeMap<(String, String, u16), u64>,
+}
+
+/// In-process registry of the counters exposed at `/metrics`
+#[derive(Debug, Default)]
+pub struct Metrics {
+    counters: Mutex<Counters>,
+}
+
+/// Escape a label value for the Prometheus text format
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Label a per-scraper counter with its scraper name
+fn by_scraper(values: &BTreeMap<String, u64>) -> impl Iterator<Item = (String, &u64)> {
+    values
+        .iter()
+        .map(|(scraper, value)| (format!("scraper=\"{}\"", escape_label(scraper)), value))
+}
+
+/// Append one counter family with its HELP and TYPE lines
+fn write_counter<'a>(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    samples: impl Iterator<Item = (String, &'a u64)>,
+) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} counter");
+    for (labels, value) in samples {
+        let _ = writeln!(out, "{name}{{{labels}}} {value}");
+    }
+}
+
+impl Metrics {
+    fn counters(&self) -> std::sync::MutexGuard<'_, Counters> {
+        self.counters.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Count the outcome of one saved scraper run
+    pub fn record_scrape(&self, scraper: &str, inserted: usize, merged: usize, failed: bool) {
+        let mut counters = self.counters();
+        *counters.competitions_inserted.entry(scraper.to_string()).or_default() += inserted as u64;
+        *counters.competitions_merged.entry(scraper.to_string()).or_default() += merged as u64;
+        if failed {
+            *counters.scraper_errors.entry(scraper.to_string()).or_default() += 1;
+        }
+    }
+
+    /// Count one handled HTTP request
+    pub fn record_request(&self, method: &str, route: &str, status: u16) {
+        *self
+            .counters()
+            .http_requests
+            .entry((method.to_string(), route.to_string(), status))
+            .or_default() += 1;
+    }
+
+    /// Render every counter in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let counters = self.counters();
+        let mut out = String::new();
+
+        write_counter(
+            &mut out,
+            "competitions_inserted_total",
+            "Competitions inserted by scraper runs",
+            by_scraper(&counters.competitions_inserted),
+        );
+        write_counter(
+            &mut out,
+            "competitions_merged_total",
+            "Scraped competitions merged into existing ones, fuzzy name matches included",
+            by_scraper(&counters.competitions_merged),
+        );
+        write_counter(
+            &mut out,
+            "scraper_errors_total",
+            "Scraper runs that failed",
+            by_scraper(&counters.scraper_errors),
+        );
+        write_counter(
+            &mut out,
+            "http_requests_total",
+            "HTTP requests handled, by method, route and status",
+            counters.http_requests.iter().map(|((method, route, status), value)| {
+                (
+                    format!(
+                        "method=\"{}\",route=\"{}\",status=\"{}\"",
+                        escape_label(method),
+                        escape_label(route),
+                        status
+                    ),
+                    value,
+                )
+            }),
+        );
+        out
+    }
+}
+
+/// Middleware counting requests by method, route template and response status.
+///
+/// The matched route (e.g. `/competitions/:id`) is used rather than the raw path so ids do not
+/// create a new series per document.
+pub async fn track_requests(State(metrics): State<Arc<Metrics>>, request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let response = next.run(request).await;
+    metrics.record_request(&method, &route, response.status().as_u16());
+    response
+}
+
+/// Serve the counters for Prometheus to scrape
+pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrape_counts_accumulate_per_scraper() {
+        let metrics = Metrics::default();
+        metrics.record_scrape("hku", 1, 2, false);
+        metrics.record_scrape("hku", 0, 3, true);
+        metrics.record_scrape("cuhk", 4, 0, false);
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("competitions_inserted_total{scraper=\"hku\"} 1\n"));
+        assert!(rendered.contains("competitions_merged_total{scraper=\"hku\"} 5\n"));
+        assert!(rendered.contains("competitions_merged_total{scraper=\"cuhk\"} 0\n"));
+        assert!(rendered.contains("scraper_errors_total{scraper=\"hku\"} 1\n"));
+        assert!(!rendered.contains("scraper_errors_total{scraper=\"cuhk\"}"));
+    }
+}
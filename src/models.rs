@@ -10,6 +10,17 @@ pub struct Competition {
     pub name: String,
     #[serde(with = "bson_datetime_as_rfc3339_string")]
     pub date: DateTime<Utc>,
+    /// Set when the source listed no readable date, so `date` is only the time of the scrape
+    /// and is ignored when matching duplicates
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub date_unknown: bool,
+    /// When the competition ends, for sources that list it. Unlike `signup_deadline` it may fall after `date`
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "option_bson_datetime_as_rfc3339_string"
+    )]
+    pub end_date: Option<DateTime<Utc>>,
     pub host: String,
     pub source: String,
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -17,7 +28,7 @@ pub struct Competition {
     #[serde(
         skip_serializing_if = "Option::is_none",
         default,
-        deserialize_with = "deserialize_optional_datetime"
+        with = "option_bson_datetime_as_rfc3339_string"
     )]
     pub signup_deadline: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -28,13 +39,273 @@ pub struct Competition {
     pub max_participants: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub status: Option<String>, // e.g., "upcoming", "active", "completed", "cancelled"
+    /// Lowercase categories such as "ctf" or "data-science"; missing in older documents
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tags: Vec<String>,
+    /// Set when the competition is archived; archived competitions are hidden from listings
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "option_bson_datetime_as_rfc3339_string"
+    )]
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Whether the "deadline soon" webhook has been sent for this competition
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub deadline_notified: bool,
+    /// Whether the competition is highlighted in `GET /competitions/featured`
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub featured: bool,
+    /// When the competition was last featured, used to drop the oldest once too many are featured
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "option_bson_datetime_as_rfc3339_string"
+    )]
+    pub featured_at: Option<DateTime<Utc>>,
+    /// When the record was first stored; missing in older documents
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "option_bson_datetime_as_rfc3339_string"
+    )]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the record was last edited or had a source merged into it
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "option_bson_datetime_as_rfc3339_string"
+    )]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Stable id from the source site, namespaced by scraper such as "ctftime:1234"
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub external_id: Option<String>,
+    /// URL-friendly unique name such as "global-case-competition-2", set when the competition is inserted
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub slug: String,
+}
+
+/// Longest slug generated from a name, before any numeric suffix
+const MAX_SLUG_LEN: usize = 80;
+
+/// Status values a competition may be stored with
+pub const COMPETITION_STATUSES: &[&str] = &["upcoming", "active", "completed", "cancelled"];
+
+impl Competition {
+    /// A competition found by a scraper, with every optional and bookkeeping field left empty.
+    /// Without a `date` the time of the scrape stands in and `date_unknown` is set.
+    pub fn scraped(
+        name: impl Into<String>,
+        host: impl Into<String>,
+        source: impl Into<String>,
+        date: Option<DateTime<Utc>>,
+    ) -> Self {
+        Competition {
+            id: None,
+            name: name.into(),
+            date: date.unwrap_or_else(Utc::now),
+            date_unknown: date.is_none(),
+            end_date: None,
+            host: host.into(),
+            source: source.into(),
+            description: None,
+            signup_deadline: None,
+            location: None,
+            registration_link: None,
+            max_participants: None,
+            status: None,
+            tags: Vec::new(),
+            deleted_at: None,
+            deadline_notified: false,
+            featured: false,
+            featured_at: None,
+            created_at: None,
+            updated_at: None,
+            external_id: None,
+            slug: String::new(),
+        }
+    }
+    
+    /// Derive the status from the event date and signup deadline relative to now.
+    ///
+    /// Competitions have no end date, so one is treated as active for a day after it starts,
+    /// or for as long as its signup deadline is still open. A stored "cancelled" status is kept.
+    pub fn computed_status(&self) -> &'static str {
+        if self.status.as_deref() == Some("cancelled") {
+            return "cancelled";
+        }
+        
+        let now = Utc::now();
+        if now < self.date {
+            "upcoming"
+        } else if self.signup_deadline.is_some_and(|deadline| now <= deadline)
+            || now < self.date + chrono::Duration::days(1)
+        {
+            "active"
+        } else {
+            "completed"
+        }
+    }
+    
+    /// Stamp a record about to be inserted with the current time as both created and updated
+    pub fn mark_created(&mut self) {
+        let now = Utc::now();
+        self.created_at = Some(now);
+        self.updated_at = Some(now);
+    }
+    
+    /// Name labelled with its sources for presentation, e.g. "Global Case Competition [HKU, HKUST]".
+    /// Names stored before sources stopped being appended already carry a label and are returned as is.
+    pub fn display_name(&self) -> String {
+        if self.source.trim().is_empty() || self.name.trim_end().ends_with(']') {
+            self.name.clone()
+        } else {
+            format!("{} [{}]", self.name, self.source)
+        }
+    }
+    
+    /// Slug derived from the name: bracketed source tags such as "[CTF]" dropped, lowercased,
+    /// and runs of other characters replaced by single hyphens. Not yet unique.
+    pub fn base_slug(&self) -> String {
+        let mut slug = String::new();
+        let mut depth = 0;
+        for c in self.name.chars().flat_map(char::to_lowercase) {
+            match c {
+                '[' => depth += 1,
+                ']' => depth = (depth - 1).max(0),
+                c if depth == 0 && c.is_alphanumeric() => slug.push(c),
+                _ if !slug.is_empty() && !slug.ends_with('-') => slug.push('-'),
+                _ => {}
+            }
+        }
+        
+        let slug: String = slug.chars().take(MAX_SLUG_LEN).collect();
+        let slug = slug.trim_matches('-');
+        if slug.is_empty() {
+            "competition".to_string()
+        } else {
+            slug.to_string()
+        }
+    }
+    
+    /// Trim and lowercase tags, dropping blanks and duplicates so filtering by tag is case insensitive
+    pub fn normalize_tags(&mut self) {
+        let mut tags: Vec<String> = Vec::with_capacity(self.tags.len());
+        for tag in self.tags.drain(..) {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        self.tags = tags;
+    }
+    
+    /// Check the competition for missing or inconsistent fields, returning every problem found
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        
+        if self.name.trim().is_empty() {
+            problems.push("name must not be empty".to_string());
+        }
+        if self.host.trim().is_empty() {
+            problems.push("host must not be empty".to_string());
+        }
+        if let Some(max) = self.max_participants
+            && max < 0
+        {
+            problems.push("max_participants must not be negative".to_string());
+        }
+        if let Some(status) = &self.status
+            && !COMPETITION_STATUSES.contains(&status.as_str())
+        {
+            problems.push(format!(
+                "status must be one of {}",
+                COMPETITION_STATUSES.join(", ")
+            ));
+        }
+        if let Some(deadline) = self.signup_deadline
+            && deadline > self.date
+        {
+            problems.push("signup_deadline must not be after date".to_string());
+        }
+        if !self.date_unknown
+            && let Some(end_date) = self.end_date
+            && end_date < self.date
+        {
+            problems.push("end_date must not be before date".to_string());
+        }
+        
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
 }
 
-// Helper module for serializing DateTime as RFC3339 string
+// Helper module for serializing DateTime as RFC3339 string.
+// Deserializing also accepts other date strings and Unix timestamps, so imported data need not be RFC3339.
 mod bson_datetime_as_rfc3339_string {
+    use std::fmt;
+
     use chrono::{DateTime, Utc};
 
-    use serde::{self, Deserialize, Deserializer, Serializer};
+    use serde::{self, de, Deserialize, Deserializer, Serializer};
+
+    /// Timestamps at or above this magnitude are epoch milliseconds rather than seconds
+    /// (as seconds it would be past the year 5000)
+    const EPOCH_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+    /// A date read from an RFC3339 string, any other format `dateparse` understands,
+    /// or an integer of epoch seconds or milliseconds
+    pub(super) struct FlexibleDate(pub DateTime<Utc>);
+
+    struct FlexibleDateVisitor;
+
+    fn from_epoch(value: i64) -> Option<DateTime<Utc>> {
+        if value.unsigned_abs() >= EPOCH_MILLIS_THRESHOLD as u64 {
+            DateTime::from_timestamp_millis(value)
+        } else {
+            DateTime::from_timestamp(value, 0)
+        }
+    }
+
+    impl de::Visitor<'_> for FlexibleDateVisitor {
+        type Value = FlexibleDate;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an RFC3339 date string or a Unix timestamp in seconds or milliseconds")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<FlexibleDate, E> {
+            value
+                .parse::<DateTime<Utc>>()
+                .ok()
+                .or_else(|| crate::dateparse::parse_date(value))
+                .map(FlexibleDate)
+                .ok_or_else(|| E::custom(format!("unrecognised date '{value}'")))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<FlexibleDate, E> {
+            from_epoch(value)
+                .map(FlexibleDate)
+                .ok_or_else(|| E::custom(format!("timestamp {value} is out of range")))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<FlexibleDate, E> {
+            i64::try_from(value)
+                .map_err(|_| E::custom(format!("timestamp {value} is out of range")))
+                .and_then(|value| self.visit_i64(value))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for FlexibleDate {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(FlexibleDateVisitor)
+        }
+    }
 
     pub fn serialize<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -47,8 +318,7 @@ mod bson_datetime_as_rfc3339_string {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        s.parse::<DateTime<Utc>>().map_err(serde::de::Error::custom)
+        FlexibleDate::deserialize(deserializer).map(|date| date.0)
     }
 }
 
@@ -58,6 +328,8 @@ mod option_bson_datetime_as_rfc3339_string {
 
     use serde::{self, Deserialize, Deserializer, Serializer};
 
+    use super::bson_datetime_as_rfc3339_string::FlexibleDate;
+
     pub fn serialize<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -72,29 +344,7 @@ mod option_bson_datetime_as_rfc3339_string {
     where
         D: Deserializer<'de>,
     {
-        let opt = Option::<String>::deserialize(deserializer).map_err(serde::de::Error::custom)?;
-        match opt {
-            Some(s) => s
-                .parse::<DateTime<Utc>>()
-                .map(Some)
-                .map_err(serde::de::Error::custom),
-            None => Ok(None),
-        }
-    }
-}
-
-// Function to deserialize optional DateTime
-fn deserialize_optional_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let opt = Option::<String>::deserialize(deserializer).map_err(serde::de::Error::custom)?;
-    match opt {
-        Some(s) => s
-            .parse::<DateTime<Utc>>()
-            .map(Some)
-            .map_err(serde::de::Error::custom),
-        None => Ok(None),
+        Option::<FlexibleDate>::deserialize(deserializer).map(|date| date.map(|date| date.0))
     }
 }
 
@@ -124,3 +374,117 @@ pub struct CompetitionResult {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub notes: Option<String>,
 }
+
+/// Persisted on/off switch for a scraper, stored in the `scraper_config` collection
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScraperSetting {
+    /// Lowercase scraper name
+    #[serde(rename = "_id")]
+    pub name: String,
+    pub enabled: bool,
+}
+
+/// Record of a single scraper execution, stored in the `scraper_runs` collection
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScraperRun {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub scraper: String,
+    #[serde(with = "bson_datetime_as_rfc3339_string")]
+    pub started_at: DateTime<Utc>,
+    #[serde(with = "bson_datetime_as_rfc3339_string")]
+    pub finished_at: DateTime<Utc>,
+    /// Competitions the scraper returned, before deduplication
+    #[serde(default)]
+    pub scraped: u32,
+    pub inserted: u32,
+    pub merged: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bson_datetime_as_rfc3339_string::FlexibleDate;
+    use super::*;
+
+    fn parse(value: serde_json::Value) -> Result<DateTime<Utc>, serde_json::Error> {
+        serde_json::from_value::<FlexibleDate>(value).map(|date| date.0)
+    }
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        text.parse().expect("valid RFC3339 date")
+    }
+
+    #[test]
+    fn flexible_date_accepts_rfc3339() {
+        assert_eq!(parse("2024-03-01T09:30:00+08:00".into()).unwrap(), utc("2024-03-01T01:30:00Z"));
+    }
+
+    #[test]
+    fn flexible_date_accepts_date_only() {
+        assert_eq!(parse("2024-03-01".into()).unwrap(), utc("2024-03-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn flexible_date_accepts_naive_datetime_as_utc() {
+        assert_eq!(parse("2024-03-01 09:30:00".into()).unwrap(), utc("2024-03-01T09:30:00Z"));
+    }
+
+    #[test]
+    fn flexible_date_accepts_epoch_seconds() {
+        assert_eq!(parse(1_709_285_400.into()).unwrap(), utc("2024-03-01T09:30:00Z"));
+    }
+
+    #[test]
+    fn flexible_date_accepts_epoch_millis() {
+        assert_eq!(parse(1_709_285_400_500_i64.into()).unwrap(), utc("2024-03-01T09:30:00.500Z"));
+    }
+
+    #[test]
+    fn flexible_date_rejects_other_values() {
+        let error = parse("next Friday".into()).unwrap_err();
+        assert!(error.to_string().contains("unrecognised date 'next Friday'"));
+        assert!(parse(serde_json::json!(true)).is_err());
+        assert!(parse(i64::MAX.into()).is_err());
+    }
+
+    fn kaggle_competition() -> Competition {
+        serde_json::from_value(serde_json::json!({
+            "name": "Kaggle Playground Series",
+            "date": "2024-03-01T00:00:00Z",
+            "end_date": "2024-03-31T23:59:00Z",
+            "host": "Kaggle",
+            "source": "Kaggle",
+            "external_id": "kaggle:playground-s4e3",
+        }))
+        .expect("valid competition")
+    }
+
+    #[test]
+    fn exported_scraped_competition_imports_again() {
+        let exported = serde_json::to_value(kaggle_competition()).unwrap();
+        let imported: Competition = serde_json::from_value(exported).unwrap();
+        
+        assert_eq!(imported.end_date, kaggle_competition().end_date);
+        assert_eq!(imported.validate(), Ok(()));
+    }
+
+    #[test]
+    fn scraped_records_still_need_the_deadline_before_the_date() {
+        let mut competition = kaggle_competition();
+        competition.signup_deadline = competition.end_date.take();
+        
+        let expected = Err(vec!["signup_deadline must not be after date".to_string()]);
+        assert_eq!(competition.validate(), expected);
+    }
+
+    #[test]
+    fn end_date_must_not_be_before_the_date() {
+        let mut competition = kaggle_competition();
+        competition.end_date = Some(competition.date - chrono::Duration::days(1));
+        
+        let expected = Err(vec!["end_date must not be before date".to_string()]);
+        assert_eq!(competition.validate(), expected);
+    }
+}
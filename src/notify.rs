@@ -0,0 +1,288 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{digest::Digest, models::Competition};
+
+/// Longest embed description Discord accepts
+const DISCORD_DESCRIPTION_LIMIT: usize = 4096;
+
+/// JSON body posted to the webhook for each new competition
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    name: &'a str,
+    host: &'a str,
+    date: String,
+    registration_link: Option<&'a str>,
+}
+
+/// JSON body posted to the webhook when a competition's signup deadline is near
+#[derive(Debug, Serialize)]
+struct DeadlinePayload<'a> {
+    event: &'static str,
+    name: &'a str,
+    host: &'a str,
+    signup_deadline: Option<String>,
+    registration_link: Option<&'a str>,
+}
+
+/// Shape of the webhook body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyTarget {
+    /// Plain JSON payload for generic webhooks
+    Json,
+    /// Discord webhook message with an embed
+    Discord,
+}
+
+impl NotifyTarget {
+    /// Parse a `NOTIFY_TARGET` value, falling back to plain JSON for anything unrecognised
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "discord" => NotifyTarget::Discord,
+            _ => NotifyTarget::Json,
+        }
+    }
+}
+
+/// Format a competition as the plain JSON webhook payload
+pub fn format_json(competition: &Competition) -> serde_json::Value {
+    json!(WebhookPayload {
+        name: &competition.name,
+        host: &competition.host,
+        date: competition.date.to_rfc3339(),
+        registration_link: competition.registration_link.as_deref(),
+    })
+}
+
+/// Format a competition as a Discord webhook message with a single embed
+pub fn format_discord(competition: &Competition) -> serde_json::Value {
+    let mut fields = vec![
+        json!({ "name": "Host", "value": competition.host, "inline": true }),
+        json!({ "name": "Date", "value": competition.date.format("%Y-%m-%d %H:%M UTC").to_string(), "inline": true }),
+    ];
+    if let Some(location) = &competition.location {
+        fields.push(json!({ "name": "Location", "value": location, "inline": true }));
+    }
+
+    let mut embed = json!({
+        "title": competition.display_name(),
+        "fields": fields,
+    });
+    if let Some(link) = &competition.registration_link {
+        embed["url"] = json!(link);
+    }
+
+    json!({ "embeds": [embed] })
+}
+
+/// Format a signup deadline reminder as the plain JSON webhook payload
+pub fn format_deadline_json(competition: &Competition) -> serde_json::Value {
+    json!(DeadlinePayload {
+        event: "deadline_soon",
+        name: &competition.name,
+        host: &competition.host,
+        signup_deadline: competition.signup_deadline.map(|deadline| deadline.to_rfc3339()),
+        registration_link: competition.registration_link.as_deref(),
+    })
+}
+
+/// Format a signup deadline reminder as a Discord webhook message with a single embed
+pub fn format_deadline_discord(competition: &Competition) -> serde_json::Value {
+    let mut message = format_discord(competition);
+    let embed = &mut message["embeds"][0];
+    embed["title"] = json!(format!("Signup closes soon: {}", competition.display_name()));
+    if let (Some(deadline), Some(fields)) = (competition.signup_deadline, embed["fields"].as_array_mut()) {
+        fields.push(json!({ "name": "Signup deadline", "value": deadline.format("%Y-%m-%d %H:%M UTC").to_string(), "inline": true }));
+    }
+    message
+}
+
+/// Format a digest as the plain JSON webhook payload, carrying both the plaintext and HTML variants
+pub fn format_digest_json(digest: &Digest) -> serde_json::Value {
+    json!({
+        "event": "digest",
+        "from": digest.from,
+        "to": digest.to,
+        "count": digest.count,
+        "text": digest.text,
+        "html": digest.html,
+    })
+}
+
+/// Format a digest as a Discord webhook message, with the plaintext variant as the embed description
+pub fn format_digest_discord(digest: &Digest) -> serde_json::Value {
+    let (title, body) = digest.text.split_once('\n').unwrap_or((&digest.text, ""));
+    let mut description: String = body.trim().chars().take(DISCORD_DESCRIPTION_LIMIT).collect();
+    if description.len() < body.trim().len() {
+        description.pop();
+        description.push('…');
+    }
+
+    json!({ "embeds": [{ "title": title, "description": description }] })
+}
+
+/// Sends outgoing webhooks when new competitions are inserted, their signup deadlines near,
+/// or a digest is due
+#[derive(Clone)]
+pub struct Notifier {
+    client: reqwest::Client,
+    webhook_url: Option<String>,
+    target: NotifyTarget,
+}
+
+impl Notifier {
+    pub fn new(client: reqwest::Client, webhook_url: Option<String>, target: NotifyTarget) -> Self {
+        Notifier {
+            client,
+            webhook_url: webhook_url.filter(|url| !url.trim().is_empty()),
+            target,
+        }
+    }
+
+    /// Build a notifier posting to `WEBHOOK_URL` in the `NOTIFY_TARGET` format,
+    /// or one that does nothing when the URL is unset
+    pub fn from_env(client: reqwest::Client) -> Self {
+        let target = std::env::var("NOTIFY_TARGET")
+            .map(|value| NotifyTarget::parse(&value))
+            .unwrap_or(NotifyTarget::Json);
+        Self::new(client, std::env::var("WEBHOOK_URL").ok(), target)
+    }
+
+    /// Whether a webhook URL is configured
+    pub fn is_enabled(&self) -> bool {
+        self.webhook_url.is_some()
+    }
+
+    /// Post each competition to the webhook in a background task so inserts never wait on it.
+    /// Failures are logged and otherwise ignored.
+    pub fn notify_new_competitions(&self, competitions: &[Competition]) {
+        let format = match self.target {
+            NotifyTarget::Json => format_json,
+            NotifyTarget::Discord => format_discord,
+        };
+        self.send(competitions, format);
+    }
+
+    /// Post a "deadline soon" reminder for one competition, waiting for the webhook to accept it
+    /// so the caller can tell whether it went out
+    pub async fn deliver_deadline_soon(&self, competition: &Competition) -> Result<(), reqwest::Error> {
+        let payload = match self.target {
+            NotifyTarget::Json => format_deadline_json(competition),
+            NotifyTarget::Discord => format_deadline_discord(competition),
+        };
+        self.deliver(payload.to_string()).await
+    }
+
+    /// Post a digest of upcoming competitions in a background task
+    pub fn notify_digest(&self, digest: &Digest) {
+        let payload = match self.target {
+            NotifyTarget::Json => format_digest_json(digest),
+            NotifyTarget::Discord => format_digest_discord(digest),
+        };
+        self.post(vec![payload.to_string()]);
+    }
+
+    fn send(&self, competitions: &[Competition], format: fn(&Competition) -> serde_json::Value) {
+        if competitions.is_empty() {
+            return;
+        }
+
+        self.post(
+            competitions
+                .iter()
+                .map(|competition| format(competition).to_string())
+                .collect(),
+        );
+    }
+
+    /// Post each JSON payload to the webhook, in order, from a background task
+    fn post(&self, payloads: Vec<String>) {
+        if !self.is_enabled() {
+            return;
+        }
+        let notifier = self.clone();
+
+        tokio::spawn(async move {
+            for payload in payloads {
+                if let Err(e) = notifier.deliver(payload).await {
+                    tracing::error!("Failed to deliver competition webhook: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Post one JSON payload to the webhook, failing on a non-success status.
+    /// Does nothing when no webhook is configured.
+    async fn deliver(&self, payload: String) -> Result<(), reqwest::Error> {
+        let Some(url) = &self.webhook_url else {
+            return Ok(());
+        };
+
+        self.client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(payload)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn competition(location: Option<&str>) -> Competition {
+        serde_json::from_value(json!({
+            "name": "Global Case Competition",
+            "date": "2024-03-01T09:30:00Z",
+            "host": "HKU Business School",
+            "source": "HKU",
+            "location": location,
+            "registration_link": "https://example.edu/apply",
+        }))
+        .expect("valid competition")
+    }
+
+    #[test]
+    fn discord_embed_has_title_link_and_fields() {
+        let message = format_discord(&competition(Some("Main Campus")));
+        let embed = &message["embeds"][0];
+
+        assert_eq!(embed["title"], "Global Case Competition [HKU]");
+        assert_eq!(embed["url"], "https://example.edu/apply");
+        assert_eq!(
+            embed["fields"],
+            json!([
+                { "name": "Host", "value": "HKU Business School", "inline": true },
+                { "name": "Date", "value": "2024-03-01 09:30 UTC", "inline": true },
+                { "name": "Location", "value": "Main Campus", "inline": true },
+            ])
+        );
+    }
+
+    #[test]
+    fn discord_embed_omits_missing_location() {
+        let message = format_discord(&competition(None));
+        let fields = message["embeds"][0]["fields"].as_array().expect("fields array");
+
+        assert_eq!(fields.len(), 2);
+        assert!(fields.iter().all(|field| field["name"] != "Location"));
+    }
+
+    #[tokio::test]
+    async fn deadline_reminder_reports_delivery_failures() {
+        let competition = competition(None);
+        let disabled = Notifier::new(reqwest::Client::new(), None, NotifyTarget::Json);
+        // Nothing listens on the discard port, so the connection is refused
+        let unreachable = Notifier::new(
+            reqwest::Client::new(),
+            Some("http://127.0.0.1:9/hook".to_string()),
+            NotifyTarget::Json,
+        );
+
+        assert!(disabled.deliver_deadline_soon(&competition).await.is_ok());
+        assert!(unreachable.deliver_deadline_soon(&competition).await.is_err());
+    }
+}
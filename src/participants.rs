@@ -0,0 +1,357 @@
+use axum::{
+    extract::{Path, Query, State},
+    middleware,
+    response::{Json, Response},
+    routing::{get, post},
+    Router,
+};
+use mongodb::{
+    bson::{doc, oid::ObjectId, Document},
+    error::{ErrorKind, WriteFailure},
+    options::IndexOptions,
+    Collection, IndexModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    auth::require_api_key,
+    competitions::{created_response, paginate, PaginatedResponse},
+    error::{ApiJson, AppError},
+    models::{Competition, Participant},
+    AppState, ApiResponse,
+};
+
+/// Query parameters for listing participants
+#[derive(Debug, Deserialize)]
+pub struct ParticipantQuery {
+    #[serde(default)]
+    pub page: Option<u32>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Request body for registering a participant.
+/// Registrations always start as "registered", so a `status` sent by the client is ignored.
+#[derive(Debug, Deserialize)]
+pub struct CreateParticipantRequest {
+    pub name: String,
+    pub email: String,
+}
+
+impl CreateParticipantRequest {
+    /// Check the name and email, returning every problem found
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        
+        if self.name.trim().is_empty() {
+            problems.push("name must not be empty".to_string());
+        }
+        if !is_plausible_email(self.email.trim()) {
+            problems.push(format!("email '{}' is not a valid email address", self.email.trim()));
+        }
+        
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
+/// Loose shape check for an email address: one `@` between a non-empty local part and a dotted domain
+fn is_plausible_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|label| !label.is_empty())
+        && !email.chars().any(char::is_whitespace)
+}
+
+/// Helper function to get participant collection reference
+fn get_participant_collection(state: &AppState) -> Collection<Participant> {
+    state.db.collection("participants")
+}
+
+/// Unique {competition_id, email} index so the same email can only register once per competition
+pub fn participant_indexes() -> Vec<IndexModel> {
+    vec![IndexModel::builder()
+        .keys(doc! { "competition_id": 1, "email": 1 })
+        .options(
+            IndexOptions::builder()
+                .unique(true)
+                .name("competition_id_email_unique".to_string())
+                .build(),
+        )
+        .build()]
+}
+
+/// Check whether a MongoDB error is a duplicate key violation of a unique index
+fn is_duplicate_key_error(error: &mongodb::error::Error) -> bool {
+    matches!(
+        error.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) if write_error.code == 11000
+    )
+}
+
+/// Conflict returned when the email already has a registration for the competition
+fn already_registered(email: &str) -> AppError {
+    AppError::Conflict(format!("{email} is already registered for this competition"))
+}
+
+/// Take one place of a capped competition by incrementing its `participant_count` only while
+/// below `max_participants`, so concurrent registrations cannot overfill it.
+///
+/// Competitions without a counter yet, or whose document was replaced since, start from the
+/// number of stored registrations.
+async fn reserve_place(
+    state: &AppState,
+    competition_id: ObjectId,
+    max_participants: i32,
+) -> Result<(), AppError> {
+    let competitions = state.db.collection::<Document>("competitions");
+    
+    let registered = get_participant_collection(state)
+        .count_documents(doc! { "competition_id": competition_id })
+        .await
+        .map_err(|e| AppError::database("Error counting participants", e))?;
+    competitions
+        .update_one(
+            doc! { "_id": competition_id, "participant_count": { "$exists": false } },
+            doc! { "$set": { "participant_count": registered as i64 } },
+        )
+        .await
+        .map_err(|e| AppError::database("Error initialising participant count", e))?;
+    
+    let reserved = competitions
+        .update_one(
+            doc! {
+                "_id": competition_id,
+                "deleted_at": null,
+                "participant_count": { "$lt": i64::from(max_participants.max(0)) },
+            },
+            doc! { "$inc": { "participant_count": 1 } },
+        )
+        .await
+        .map_err(|e| AppError::database("Error reserving a participant place", e))?;
+    if reserved.modified_count == 0 {
+        return Err(AppError::Conflict(format!(
+            "Competition is full: all {max_participants} places are taken"
+        )));
+    }
+    Ok(())
+}
+
+/// Give back a place taken by `reserve_place` when the registration could not be stored
+async fn release_place(state: &AppState, competition_id: ObjectId) {
+    let released = state
+        .db
+        .collection::<Document>("competitions")
+        .update_one(
+            doc! { "_id": competition_id, "participant_count": { "$gt": 0 } },
+            doc! { "$inc": { "participant_count": -1 } },
+        )
+        .await;
+    if let Err(e) = released {
+        tracing::error!("Failed to release a participant place for {}: {}", competition_id, e);
+    }
+}
+
+/// Register a participant for a competition, enforcing its max_participants limit
+pub async fn create_participant(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<CreateParticipantRequest>,
+) -> Result<Response, AppError> {
+    let competition_id = AppError::parse_object_id(&id)?;
+    request.validate().map_err(AppError::Validation)?;
+    
+    // Make sure the parent competition exists and has not been archived
+    let competition = state
+        .db
+        .collection::<Competition>("competitions")
+        .find_one(doc! { "_id": competition_id, "deleted_at": null })
+        .await
+        .map_err(|e| AppError::database("Error finding competition by ID", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    let collection = get_participant_collection(&state);
+    
+    // Emails are stored lowercased so the unique index is effectively case-insensitive
+    let email = request.email.trim().to_lowercase();
+    
+    // Reject a second registration with the same email, including older mixed-case records
+    let existing = collection
+        .find_one(doc! {
+            "competition_id": competition_id,
+            "email": { "$regex": format!("^{}$", regex::escape(&email)), "$options": "i" },
+        })
+        .await
+        .map_err(|e| AppError::database("Error checking for existing participant", e))?;
+    if existing.is_some() {
+        return Err(already_registered(&email));
+    }
+    
+    // Reject the registration when the competition is already full
+    if let Some(max_participants) = competition.max_participants {
+        reserve_place(&state, competition_id, max_participants).await?;
+    }
+    
+    let mut participant = Participant {
+        id: None,
+        name: request.name.trim().to_string(),
+        email,
+        competition_id,
+        registration_date: chrono::Utc::now(),
+        status: Some("registered".to_string()),
+    };
+    
+    let inserted = collection.insert_one(participant.clone()).await;
+    if inserted.is_err() && competition.max_participants.is_some() {
+        release_place(&state, competition_id).await;
+    }
+    let result = inserted.map_err(|e| {
+        if is_duplicate_key_error(&e) {
+            // Lost a race with a concurrent registration for the same email
+            return already_registered(&participant.email);
+        }
+        AppError::database("Failed to insert participant", e)
+    })?;
+    participant.id = result.inserted_id.as_object_id();
+    state.mailer.send_registration_confirmation(&participant, &competition);
+    
+    let location = participant
+        .id
+        .map(|participant_id| format!("/competitions/{}/participants/{}", competition_id.to_hex(), participant_id.to_hex()));
+    Ok(created_response(location, ApiResponse {
+        success: true,
+        data: Some(participant),
+        message: Some("Participant registered successfully".to_string()),
+    }))
+}
+
+/// Get one participant of a competition
+pub async fn get_participant(
+    State(state): State<AppState>,
+    Path((id, participant_id)): Path<(String, String)>,
+) -> Result<Json<ApiResponse<Participant>>, AppError> {
+    let competition_id = AppError::parse_object_id(&id)?;
+    let participant_id = AppError::parse_object_id(&participant_id)?;
+    
+    let participant = get_participant_collection(&state)
+        .find_one(doc! { "_id": participant_id, "competition_id": competition_id })
+        .await
+        .map_err(|e| AppError::database("Error finding participant", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(participant),
+        message: Some("Participant retrieved successfully".to_string()),
+    }))
+}
+
+/// List participants registered for a competition, optionally filtered by status
+pub async fn get_participants(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ParticipantQuery>,
+) -> Result<Json<ApiResponse<PaginatedResponse<Participant>>>, AppError> {
+    let competition_id = AppError::parse_object_id(&id)?;
+    
+    let mut filter = doc! { "competition_id": competition_id };
+    if let Some(status) = &query.status {
+        filter.insert("status", status);
+    }
+    
+    let limit = state.pagination.limit(query.limit);
+    let participants = paginate(
+        &get_participant_collection(&state),
+        filter,
+        query.page,
+        limit,
+        doc! { "registration_date": 1 },
+    )
+    .await?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(participants),
+        message: Some("Participants retrieved successfully".to_string()),
+    }))
+}
+
+/// Create the router for participant routes, nested under /competitions.
+/// Anyone may register, but reading registrations exposes emails and requires an API key.
+pub fn create_participant_router() -> Router<AppState> {
+    let protected = Router::new()
+        .route("/:id/participants", get(get_participants))
+        .route("/:id/participants/:participant_id", get(get_participant))
+        .route_layer(middleware::from_fn(require_api_key));
+    
+    Router::new()
+        .route("/:id/participants", post(create_participant))
+        .merge(protected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(name: &str, email: &str) -> CreateParticipantRequest {
+        CreateParticipantRequest { name: name.to_string(), email: email.to_string() }
+    }
+
+    #[test]
+    fn plausible_emails_are_accepted() {
+        for email in ["taiman@example.com", "a.b+c@mail.example.hk"] {
+            assert!(is_plausible_email(email), "{email}");
+        }
+    }
+
+    #[test]
+    fn malformed_emails_are_rejected() {
+        let emails = [
+            "",
+            "taiman",
+            "@example.com",
+            "taiman@",
+            "taiman@localhost",
+            "a@b@example.com",
+            "tai man@example.com",
+            "taiman@example..com",
+        ];
+        for email in emails {
+            assert!(!is_plausible_email(email), "{email}");
+        }
+    }
+
+    #[test]
+    fn registration_reports_every_problem() {
+        assert_eq!(request("Chan Tai Man", " taiman@example.com ").validate(), Ok(()));
+        assert_eq!(
+            request("  ", "taiman").validate(),
+            Err(vec![
+                "name must not be empty".to_string(),
+                "email 'taiman' is not a valid email address".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn client_status_is_ignored() {
+        let request: CreateParticipantRequest = serde_json::from_value(serde_json::json!({
+            "name": "Chan Tai Man",
+            "email": "taiman@example.com",
+            "status": "confirmed",
+        }))
+        .expect("unknown fields are ignored");
+        
+        assert_eq!(request.name, "Chan Tai Man");
+    }
+}
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use futures_util::TryStreamExt;
+use mongodb::{
+    bson::{doc, oid::ObjectId},
+    Collection, Database,
+};
+use tokio::{sync::watch, task::JoinHandle};
+
+use crate::{models::Competition, notify::Notifier};
+
+/// Settings for the signup deadline reminder job
+#[derive(Debug, Clone)]
+pub struct ReminderConfig {
+    /// Competitions whose signup deadline falls within this window from now are announced
+    pub window: chrono::Duration,
+    /// How often to scan for competitions entering the window
+    pub interval: Duration,
+}
+
+impl Default for ReminderConfig {
+    fn default() -> Self {
+        ReminderConfig {
+            window: chrono::Duration::hours(48),
+            interval: Duration::from_secs(3600),
+        }
+    }
+}
+
+impl ReminderConfig {
+    /// Build the config from `REMINDER_WINDOW_HOURS` and `REMINDER_INTERVAL_SECS`, falling back to defaults
+    pub fn from_env() -> Self {
+        let mut config = ReminderConfig::default();
+        let number = |key: &str| std::env::var(key).ok().and_then(|v| v.parse::<u64>().ok());
+
+        if let Some(hours) = number("REMINDER_WINDOW_HOURS") {
+            config.window = chrono::Duration::hours(hours.try_into().unwrap_or(i64::MAX / 3600));
+        }
+        if let Some(secs) = number("REMINDER_INTERVAL_SECS").filter(|secs| *secs > 0) {
+            config.interval = Duration::from_secs(secs);
+        }
+        config
+    }
+}
+
+/// Find competitions whose signup deadline is within the window and mark each as notified.
+///
+/// Each competition is claimed with a conditional update, so one announced by another
+/// instance in the meantime is skipped rather than sent twice. Claims whose reminder then
+/// fails to send are given back with `release_reminder`.
+async fn claim_due_reminders(
+    collection: &Collection<Competition>,
+    window: chrono::Duration,
+) -> Result<Vec<Competition>, mongodb::error::Error> {
+    let now = Utc::now();
+    let filter = doc! {
+        // Dates are stored as RFC3339 strings, so compare against the same representation
+        "signup_deadline": { "$gte": now.to_rfc3339(), "$lte": (now + window).to_rfc3339() },
+        "deadline_notified": { "$ne": true },
+        "deleted_at": null,
+    };
+    let due: Vec<Competition> = collection.find(filter).await?.try_collect().await?;
+
+    let mut claimed = Vec::with_capacity(due.len());
+    for competition in due {
+        let Some(id) = competition.id else {
+            continue;
+        };
+        let result = collection
+            .update_one(
+                doc! { "_id": id, "deadline_notified": { "$ne": true } },
+                doc! { "$set": { "deadline_notified": true } },
+            )
+            .await?;
+        if result.modified_count == 1 {
+            claimed.push(competition);
+        }
+    }
+    Ok(claimed)
+}
+
+/// Clear a claim made by `claim_due_reminders` after its reminder failed to send,
+/// so the next scan tries again while the deadline is still within the window
+async fn release_reminder(collection: &Collection<Competition>, id: ObjectId) -> Result<(), mongodb::error::Error> {
+    collection
+        .update_one(doc! { "_id": id }, doc! { "$unset": { "deadline_notified": "" } })
+        .await?;
+    Ok(())
+}
+
+/// Send a reminder for each claimed competition, releasing the claims of those that failed
+async fn send_reminders(collection: &Collection<Competition>, notifier: &Notifier, competitions: Vec<Competition>) {
+    for competition in competitions {
+        let Err(e) = notifier.deliver_deadline_soon(&competition).await else {
+            continue;
+        };
+        tracing::error!("Failed to send deadline reminder for {}: {}", competition.name, e);
+        if let Some(id) = competition.id
+            && let Err(e) = release_reminder(collection, id).await
+        {
+            tracing::error!("Failed to release deadline reminder for {}: {}", competition.name, e);
+        }
+    }
+}
+
+/// Start the background job that sends a "deadline soon" webhook once per competition.
+///
+/// Nothing is started when no webhook is configured, so competitions are not marked as
+/// notified without a reminder actually going out. The job stops once `shutdown` changes,
+/// finishing any scan already underway.
+pub fn spawn_deadline_reminders(
+    db: Database,
+    notifier: Notifier,
+    config: ReminderConfig,
+    mut shutdown: watch::Receiver<bool>,
+) -> Option<JoinHandle<()>> {
+    if !notifier.is_enabled() {
+        tracing::info!("WEBHOOK_URL is not set, deadline reminders are disabled");
+        return None;
+    }
+
+    tracing::info!(
+        "Scanning for signup deadlines within {} hours every {} seconds",
+        config.window.num_hours(),
+        config.interval.as_secs()
+    );
+    let collection: Collection<Competition> = db.collection("competitions");
+
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(config.interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = shutdown.changed() => break,
+                _ = ticker.tick() => {}
+            }
+            match claim_due_reminders(&collection, config.window).await {
+                Ok(competitions) => {
+                    if !competitions.is_empty() {
+                        tracing::info!("Sending {} deadline reminders", competitions.len());
+                    }
+                    send_reminders(&collection, &notifier, competitions).await;
+                }
+                Err(e) => tracing::error!("Failed to scan for signup deadline reminders: {}", e),
+            }
+        }
+    }))
+}
@@ -0,0 +1,238 @@
+use axum::{
+    extract::{Path, Query, State},
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use futures_util::TryStreamExt;
+use mongodb::{Collection, bson::{doc, oid::ObjectId}};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    auth::require_api_key,
+    competitions::{paginate, PaginatedResponse},
+    error::{ApiJson, AppError},
+    models::{Competition, CompetitionResult, Participant},
+    AppState, ApiResponse,
+};
+
+/// A single score submitted for a participant
+#[derive(Debug, Deserialize)]
+pub struct ResultSubmission {
+    pub participant_id: String,
+    pub score: f64,
+}
+
+/// A single row of a competition leaderboard
+#[derive(Debug, Serialize)]
+pub struct LeaderboardEntry {
+    pub rank: i32,
+    pub participant_name: String,
+    pub score: Option<f64>,
+    pub notes: Option<String>,
+}
+
+/// Query parameters for paging through a leaderboard
+#[derive(Debug, Deserialize)]
+pub struct ResultQuery {
+    #[serde(default)]
+    pub page: Option<u32>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+/// Helper function to get result collection reference
+fn get_result_collection(state: &AppState) -> Collection<CompetitionResult> {
+    state.db.collection("results")
+}
+
+/// Join results with participant names, falling back to the raw participant id when missing
+async fn build_leaderboard(
+    state: &AppState,
+    results: Vec<CompetitionResult>,
+) -> Result<Vec<LeaderboardEntry>, AppError> {
+    let participant_ids: Vec<ObjectId> = results.iter().map(|r| r.participant_id).collect();
+    
+    let participants: Vec<Participant> = state
+        .db
+        .collection::<Participant>("participants")
+        .find(doc! { "_id": { "$in": participant_ids } })
+        .await
+        .map_err(|e| AppError::database("Error finding participants for leaderboard", e))?
+        .try_collect()
+        .await
+        .map_err(|e| AppError::database("Error fetching participant from cursor", e))?;
+    
+    let names: HashMap<ObjectId, String> = participants
+        .into_iter()
+        .filter_map(|p| p.id.map(|id| (id, p.name)))
+        .collect();
+    
+    Ok(results
+        .into_iter()
+        .map(|result| LeaderboardEntry {
+            rank: result.rank,
+            participant_name: names
+                .get(&result.participant_id)
+                .cloned()
+                .unwrap_or_else(|| result.participant_id.to_hex()),
+            score: result.score,
+            notes: result.notes,
+        })
+        .collect())
+}
+
+/// Get one page of the leaderboard for a competition, sorted by rank
+pub async fn get_results(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ResultQuery>,
+) -> Result<Json<ApiResponse<PaginatedResponse<LeaderboardEntry>>>, AppError> {
+    let competition_id = AppError::parse_object_id(&id)?;
+    
+    let limit = state.pagination.limit(query.limit);
+    let results = paginate(
+        &get_result_collection(&state),
+        doc! { "competition_id": competition_id },
+        query.page,
+        limit,
+        doc! { "rank": 1, "_id": 1 },
+    )
+    .await?;
+    
+    let entries = build_leaderboard(&state, results.data).await?;
+    let leaderboard = PaginatedResponse::new(entries, results.page, results.limit, results.total);
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(leaderboard),
+        message: Some("Leaderboard retrieved successfully".to_string()),
+    }))
+}
+
+/// Rank scores in descending order, giving tied scores the same rank (e.g. 1, 2, 2, 4)
+fn assign_ranks(mut scores: Vec<(ObjectId, f64)>) -> Vec<(ObjectId, f64, i32)> {
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    
+    let mut ranked = Vec::with_capacity(scores.len());
+    let mut previous_score: Option<f64> = None;
+    let mut rank = 0;
+    
+    for (position, (participant_id, score)) in scores.into_iter().enumerate() {
+        if previous_score != Some(score) {
+            rank = position as i32 + 1;
+            previous_score = Some(score);
+        }
+        ranked.push((participant_id, score, rank));
+    }
+    
+    ranked
+}
+
+/// Submit scores for a competition, ranking them server-side and replacing any previous results
+pub async fn submit_results(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    ApiJson(submissions): ApiJson<Vec<ResultSubmission>>,
+) -> Result<Json<ApiResponse<Vec<LeaderboardEntry>>>, AppError> {
+    let competition_id = AppError::parse_object_id(&id)?;
+    
+    // Make sure the competition exists and has not been archived
+    state
+        .db
+        .collection::<Competition>("competitions")
+        .find_one(doc! { "_id": competition_id, "deleted_at": null })
+        .await
+        .map_err(|e| AppError::database("Error finding competition by ID", e))?
+        .ok_or(AppError::NotFound)?;
+    
+    // Parse participant ids, collecting every malformed or repeated entry
+    let mut scores = Vec::with_capacity(submissions.len());
+    let mut seen = HashSet::new();
+    let mut problems = Vec::new();
+    for submission in submissions {
+        match ObjectId::parse_str(&submission.participant_id) {
+            Ok(participant_id) if seen.insert(participant_id) => scores.push((participant_id, submission.score)),
+            Ok(_) => problems.push(format!("participant {} is listed more than once", submission.participant_id)),
+            Err(_) => problems.push(format!("participant id '{}' is not a valid ObjectId", submission.participant_id)),
+        }
+    }
+    if !problems.is_empty() {
+        return Err(AppError::Validation(problems));
+    }
+    
+    // Every participant must be registered for this competition
+    let participant_ids: Vec<ObjectId> = scores.iter().map(|(participant_id, _)| *participant_id).collect();
+    let registered: HashSet<ObjectId> = state
+        .db
+        .collection::<Participant>("participants")
+        .distinct("_id", doc! {
+            "_id": { "$in": &participant_ids },
+            "competition_id": competition_id,
+        })
+        .await
+        .map_err(|e| AppError::database("Error finding registered participants", e))?
+        .into_iter()
+        .filter_map(|id| id.as_object_id())
+        .collect();
+    let unregistered: Vec<String> = participant_ids
+        .iter()
+        .filter(|participant_id| !registered.contains(participant_id))
+        .map(|participant_id| format!("participant {} is not registered for this competition", participant_id.to_hex()))
+        .collect();
+    if !unregistered.is_empty() {
+        return Err(AppError::Validation(unregistered));
+    }
+    
+    // ObjectIds increase over time, so every row of this submission sorts after the cutoff
+    // and every row of an earlier submission sorts before it
+    let cutoff = ObjectId::new();
+    let results: Vec<CompetitionResult> = assign_ranks(scores)
+        .into_iter()
+        .map(|(participant_id, score, rank)| CompetitionResult {
+            id: Some(ObjectId::new()),
+            competition_id,
+            participant_id,
+            rank,
+            score: Some(score),
+            notes: None,
+        })
+        .collect();
+    
+    let collection = get_result_collection(&state);
+    
+    // Ranks are computed over the whole submission, so it replaces any earlier results. The new rows
+    // go in first so a failure never leaves the competition without a leaderboard, then everything
+    // older than the cutoff is removed; when two submissions race, the later one wins outright.
+    if !results.is_empty() {
+        collection
+            .insert_many(results.clone())
+            .await
+            .map_err(|e| AppError::database("Failed to insert results", e))?;
+    }
+    collection
+        .delete_many(doc! { "competition_id": competition_id, "_id": { "$lt": cutoff } })
+        .await
+        .map_err(|e| AppError::database("Error clearing previous results", e))?;
+    
+    let leaderboard = build_leaderboard(&state, results).await?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(leaderboard),
+        message: Some("Results submitted successfully".to_string()),
+    }))
+}
+
+/// Create the router for result routes, nested under /competitions. Submitting results requires an API key
+pub fn create_result_router() -> Router<AppState> {
+    let protected = Router::new()
+        .route("/:id/results", post(submit_results))
+        .route_layer(middleware::from_fn(require_api_key));
+    
+    Router::new()
+        .route("/:id/results", get(get_results))
+        .merge(protected)
+}
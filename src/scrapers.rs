@@ -1,22 +1,116 @@
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    middleware,
     response::Json,
     routing::{get, post},
     Router,
 };
 use futures_util::TryStreamExt;
-use mongodb::{Collection, bson::doc};
+use mongodb::{Collection, IndexModel, bson::{doc, oid::ObjectId, Document}, options::IndexOptions};
+use rust_stemmers::{Algorithm, Stemmer};
 use scraper::{Html, Selector};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
 
-use crate::{models::Competition, AppState, ApiResponse};
+use crate::{
+    auth::require_api_key,
+    competitions::{assign_slugs, insert_write_errors},
+    dateparse,
+    error::AppError,
+    models::{Competition, ScraperRun, ScraperSetting},
+    notify::Notifier,
+    AppState, ApiResponse,
+};
 
 /// Trait that defines the interface for all scrapers
 #[async_trait::async_trait]
 pub trait Scraper: Send + Sync {
-    async fn scrape(&self, db: &mongodb::Database) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>>;
-    fn name(&self) -> &'static str;
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>>;
+    fn name(&self) -> &str;
+    fn source_url(&self) -> &str;
+}
+
+/// Name, target URL and on/off state of a registered scraper
+#[derive(Debug, Serialize)]
+pub struct ScraperInfo {
+    pub name: String,
+    pub source_url: String,
+    pub enabled: bool,
+}
+
+/// Whether a scraper's source answered a lightweight probe, and how quickly
+#[derive(Debug, Serialize)]
+pub struct ScraperReachability {
+    pub name: String,
+    pub reachable: bool,
+    /// HTTP status of the probe, missing when no response arrived
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+}
+
+/// How long each reachability probe waits for a source to respond
+const REACHABILITY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Errors a scraper raises itself, as opposed to network or decoding failures
+#[derive(Debug)]
+pub enum ScrapeError {
+    /// A hardcoded CSS selector failed to parse
+    InvalidSelector { selector: String, reason: String },
+    /// A page that returned competitions on an earlier run returned none, which usually means its markup changed
+    EmptyResult { scraper: String, previous: usize },
+}
+
+impl std::fmt::Display for ScrapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScrapeError::InvalidSelector { selector, reason } => {
+                write!(f, "invalid selector '{selector}': {reason}")
+            }
+            ScrapeError::EmptyResult { scraper, previous } => write!(
+                f,
+                "scraper {scraper} found no competitions after finding {previous} on its last run; the page markup may have changed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScrapeError {}
+
+/// Parse a CSS selector, returning an error instead of panicking when it is invalid
+fn parse_selector(selector: &str) -> Result<Selector, ScrapeError> {
+    Selector::parse(selector).map_err(|e| ScrapeError::InvalidSelector {
+        selector: selector.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Title keywords that mark a university listing entry as a competition rather than a general event
+const COMPETITION_KEYWORDS: &[&str] = &["Case", "Challenge", "Competition", "Hackathon", "Datathon"];
+
+/// Whether the title contains any of the keywords, ignoring case
+fn title_matches_keywords<K: AsRef<str>>(title: &str, keywords: &[K]) -> bool {
+    let title = title.to_lowercase();
+    keywords
+        .iter()
+        .any(|keyword| title.contains(&keyword.as_ref().to_lowercase()))
+}
+
+/// Resolve a link found on a page against the page URL, so relative hrefs become absolute
+fn absolutize_link(base: &str, href: &str) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+        return None;
+    }
+    
+    reqwest::Url::parse(base)
+        .and_then(|base| base.join(href))
+        .ok()
+        .map(|url| url.to_string())
 }
 
 /// HKU Scraper implementation
@@ -24,74 +118,119 @@ pub struct HkuScraper;
 
 #[async_trait::async_trait]
 impl Scraper for HkuScraper {
-    async fn scrape(&self, db: &mongodb::Database) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
-        let url = "https://ug.hkubs.hku.hk/competition";
-        
-        // Create a client that can handle SSL verification with rustls
-        let client = reqwest::Client::builder()
-            .danger_accept_invalid_certs(true)
-            .use_rustls_tls()
-            .build()?;
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
         
         // Fetch the page
-        let response = client.get(url).send().await?;
+        let response = client.get(url).send().await?.error_for_status()?;
         let body = response.text().await?;
         
-        // Parse HTML and extract titles synchronously to avoid Send issues
-        let titles = {
+        // Parse HTML and extract titles, blurbs and links synchronously to avoid Send issues
+        let cards = {
             // Parse HTML
             let document = Html::parse_document(&body);
             
-            // Create selector for competition cards
-            let card_selector = Selector::parse("a.card-blk__item").unwrap();
-            let title_selector = Selector::parse("p.card-blk__title").unwrap();
+            // Create selectors for competition cards and their title and blurb
+            let card_selector = parse_selector("a.card-blk__item")?;
+            let title_selector = parse_selector("p.card-blk__title")?;
+            let description_selector = parse_selector(".card-blk__desc, .card-blk__text, .card-blk__summary")?;
             
-            // Collect titles
+            // Collect titles, blurbs and card links, dropping info sessions and other non-competition events
             document
                 .select(&card_selector)
                 .filter_map(|card| {
-                    card.select(&title_selector).next()
+                    let title_element = card.select(&title_selector).next()?;
+                    let title = title_element.text().collect::<Vec<_>>().join(" ").trim().to_string();
+                    // Collapse the line breaks and indentation of the markup into single spaces
+                    let description = card
+                        .select(&description_selector)
+                        .next()
+                        .map(|element| element.text().flat_map(str::split_whitespace).collect::<Vec<_>>().join(" "))
+                        .filter(|description| !description.is_empty());
+                    let link = card.value().attr("href").and_then(|href| absolutize_link(url, href));
+                    Some((title, description, link))
                 })
-                .map(|title_element| {
-                    title_element.text().collect::<Vec<_>>().join(" ").trim().to_string()
-                })
-                .collect::<Vec<String>>()
+                .filter(|(title, _, _)| title_matches_keywords(title, COMPETITION_KEYWORDS))
+                .collect::<Vec<_>>()
         }; // HTML document is dropped here, so no Send issues
         
         let mut competitions = Vec::new();
         
         // Process each title
-        for title in titles {
+        for (title, description, link) in cards {
             // Create competition with HKU source
             let competition = Competition {
-                id: None, // Will be set by MongoDB
-                name: format!("{title} [HKU]"),
-                date: chrono::Utc::now(), // Default to current time, should be parsed from actual date if available
-                host: "HKU".to_string(), // Keep as HKU as requested
-                source: "HKU".to_string(),
-                description: None,
-                signup_deadline: None,
-                location: None,
-                registration_link: None,
-                max_participants: None,
+                description,
+                registration_link: link,
                 status: Some("upcoming".to_string()),
+                tags: vec!["business".to_string()],
+                ..Competition::scraped(title, "HKU", "HKU", None)
             };
             
-            // Use fuzzy matching to check for duplicates
-            if !is_duplicate_competition(db, &competition).await {
-                competitions.push(competition);
-            } else {
-                // If it's a duplicate, update the source field to include HKU
-                update_existing_competition_source(db, &competition.name, "HKU").await?;
-            }
+            competitions.push(competition);
         }
         
         Ok(competitions)
     }
 
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "HKU"
     }
+
+    fn source_url(&self) -> &str {
+        "https://ug.hkubs.hku.hk/competition"
+    }
+}
+
+/// Parse the HKUST announcement table, keeping rows whose titles look like competitions.
+/// Relative links are resolved against `base`.
+fn parse_hkust_announcements(body: &str, base: &str) -> Result<Vec<Competition>, ScrapeError> {
+    // Parse HTML
+    let document = Html::parse_document(body);
+    
+    // Create selectors for announcement rows and their cells
+    let row_selector = parse_selector("tr")?;
+    let title_selector = parse_selector("h3")?;
+    let cell_selector = parse_selector("td")?;
+    let link_selector = parse_selector("a[href]")?;
+    
+    // Collect titles that match keywords along with their row's date and link
+    let mut announcements = Vec::new();
+    
+    for row in document.select(&row_selector) {
+        // The date column is the first cell in the row holding a parseable date
+        let date = row
+            .select(&cell_selector)
+            .find_map(|cell| dateparse::parse_with_tz(&cell.text().collect::<Vec<_>>().join(" "), dateparse::HKT));
+        let link = row
+            .select(&link_selector)
+            .find_map(|anchor| anchor.value().attr("href").and_then(|href| absolutize_link(base, href)));
+        
+        for title_element in row.select(&title_selector) {
+            let title_text = title_element.text().collect::<Vec<_>>().join(" ").trim().to_string();
+            
+            if title_matches_keywords(&title_text, COMPETITION_KEYWORDS) {
+                announcements.push((title_text, date, link.clone()));
+            }
+        }
+    }
+    
+    let mut competitions = Vec::new();
+    
+    // Process each matching title
+    for (title, date, link) in announcements {
+        // Create competition with HKUST source
+        let competition = Competition {
+            registration_link: link,
+            status: Some("upcoming".to_string()),
+            tags: vec!["business".to_string()],
+            ..Competition::scraped(title, "HKUST", "HKUST", date)
+        };
+        
+        competitions.push(competition);
+    }
+    
+    Ok(competitions)
 }
 
 /// HKUST Scraper implementation
@@ -99,110 +238,317 @@ pub struct HkustScraper;
 
 #[async_trait::async_trait]
 impl Scraper for HkustScraper {
-    async fn scrape(&self, db: &mongodb::Database) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
-        let url = "https://bmundergrad.hkust.edu.hk/announcement";
-        
-        // Create a client that can handle SSL verification with rustls
-        let client = reqwest::Client::builder()
-            .danger_accept_invalid_certs(true)  // Equivalent to verify=False in Python
-            .use_rustls_tls()
-            .build()?;
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
         
         // Fetch the page
-        let response = client.get(url).send().await?;
+        let response = client.get(url).send().await?.error_for_status()?;
         let body = response.text().await?;
         
-        // Keywords to filter for
-        let keywords = [
-            "Case", "Challenge", "Competition", "Hackathon", "Datathon"
-        ];
-        
-        // Parse HTML and extract titles synchronously to avoid Send issues
-        let titles = {
-            // Parse HTML
-            let document = Html::parse_document(&body);
-            
-            // Create selector for announcement rows
-            let row_selector = Selector::parse("tr").unwrap();
-            let title_selector = Selector::parse("h3").unwrap();
-            
-            // Collect titles that match keywords
-            let mut matching_titles = Vec::new();
-            
-            for row in document.select(&row_selector) {
-                for title_element in row.select(&title_selector) {
-                    let title_text = title_element.text().collect::<Vec<_>>().join(" ").trim().to_string();
-                    
-                    // Check if any keyword is in the title (case insensitive)
-                    if keywords.iter().any(|&keyword| {
-                        title_text.to_lowercase().contains(&keyword.to_lowercase())
-                    }) {
-                        matching_titles.push(title_text);
-                    }
-                }
-            }
-            
-            matching_titles
-        }; // HTML document is dropped here, so no Send issues
-        
-        let mut competitions = Vec::new();
+        Ok(parse_hkust_announcements(&body, url)?)
+    }
+
+    fn name(&self) -> &str {
+        "HKUST"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://bmundergrad.hkust.edu.hk/announcement"
+    }
+}
+
+/// Default minimum string similarity for two names to be considered duplicates
+const DEFAULT_SIMILARITY_THRESHOLD: f64 = 0.75;
+
+/// Default minimum similarity for two words to count as shared between names
+const DEFAULT_WORD_SIMILARITY_THRESHOLD: f64 = 0.7;
+
+/// Default minimum fraction of the longer name's words that must be shared
+const DEFAULT_WORD_OVERLAP_THRESHOLD: f64 = 0.5;
+
+/// Default minimum fraction of all distinct words that must be shared
+const DEFAULT_UNIQUE_WORD_RATIO_THRESHOLD: f64 = 0.4;
+
+/// Default number of days two dates may differ by and still belong to the same event
+const DEFAULT_DATE_WINDOW_DAYS: i64 = 30;
+
+/// Tuning for duplicate detection between scraped and stored competitions
+#[derive(Debug, Clone)]
+pub struct MatchConfig {
+    /// Lowercase words ignored when comparing names
+    pub stop_words: HashSet<String>,
+    pub similarity_threshold: f64,
+    pub word_similarity_threshold: f64,
+    pub word_overlap_threshold: f64,
+    pub unique_word_ratio_threshold: f64,
+    /// Maximum distance between the dates of two competitions for them to be duplicates,
+    /// so a yearly event is not merged with last year's edition
+    pub date_window: chrono::Duration,
+    /// Reduce words to their English stems before the word overlap check,
+    /// so variants like "trading" and "trades" count as the same word
+    pub stem_words: bool,
+    /// Lowercase low-information phrases such as "information session"; a name made up only of
+    /// these and stop words never matches another, however similar
+    pub blocked_phrases: HashSet<String>,
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        MatchConfig {
+            stop_words: DEFAULT_STOP_WORDS.iter().map(|word| word.to_string()).collect(),
+            similarity_threshold: DEFAULT_SIMILARITY_THRESHOLD,
+            word_similarity_threshold: DEFAULT_WORD_SIMILARITY_THRESHOLD,
+            word_overlap_threshold: DEFAULT_WORD_OVERLAP_THRESHOLD,
+            unique_word_ratio_threshold: DEFAULT_UNIQUE_WORD_RATIO_THRESHOLD,
+            date_window: chrono::Duration::days(DEFAULT_DATE_WINDOW_DAYS),
+            stem_words: true,
+            blocked_phrases: DEFAULT_BLOCKED_PHRASES.iter().map(|phrase| phrase.to_string()).collect(),
+        }
+    }
+}
+
+impl MatchConfig {
+    /// Build the config from environment variables, falling back to defaults.
+    /// `MATCH_STOP_WORDS` and `MATCH_BLOCKED_PHRASES` are comma-separated lists that replace
+    /// the default stop words and blocked phrases.
+    pub fn from_env() -> Self {
+        let mut config = MatchConfig::default();
+        let threshold = |key: &str| std::env::var(key).ok().and_then(|v| v.parse::<f64>().ok());
         
-        // Process each matching title
-        for title in titles {
-            // Create competition with HKUST source
-            let competition = Competition {
-                id: None, // Will be set by MongoDB
-                name: format!("{title} [UST]"),
-                date: chrono::Utc::now(), // Default to current time, should be parsed from actual date if available
-                host: "HKUST".to_string(), // Keep as HKUST as requested
-                source: "HKUST".to_string(),
-                description: None,
-                signup_deadline: None,
-                location: None,
-                registration_link: None,
-                max_participants: None,
-                status: Some("upcoming".to_string()),
-            };
-            
-            // Use fuzzy matching to check for duplicates
-            if !is_duplicate_competition(db, &competition).await {
-                competitions.push(competition);
-            } else {
-                // If it's a duplicate, update the source field to include HKUST
-                update_existing_competition_source(db, &competition.name, "HKUST").await?;
-            }
+        if let Some(value) = threshold("MATCH_SIMILARITY_THRESHOLD") {
+            config.similarity_threshold = value;
+        }
+        if let Some(value) = threshold("MATCH_WORD_SIMILARITY_THRESHOLD") {
+            config.word_similarity_threshold = value;
+        }
+        if let Some(value) = threshold("MATCH_WORD_OVERLAP_THRESHOLD") {
+            config.word_overlap_threshold = value;
+        }
+        if let Some(value) = threshold("MATCH_UNIQUE_WORD_RATIO_THRESHOLD") {
+            config.unique_word_ratio_threshold = value;
+        }
+        if let Some(days) = std::env::var("MATCH_DATE_WINDOW_DAYS").ok().and_then(|v| v.parse::<i64>().ok()) {
+            config.date_window = chrono::Duration::days(days.clamp(0, 36_500));
+        }
+        if let Ok(value) = std::env::var("MATCH_STEMMING") {
+            config.stem_words = !matches!(value.trim().to_lowercase().as_str(), "0" | "false" | "no" | "off");
+        }
+        if let Ok(words) = std::env::var("MATCH_STOP_WORDS") {
+            config.stop_words = words
+                .split(',')
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect();
+        }
+        if let Ok(phrases) = std::env::var("MATCH_BLOCKED_PHRASES") {
+            config.blocked_phrases = phrases
+                .split(',')
+                .map(|phrase| phrase.trim().to_lowercase())
+                .filter(|phrase| !phrase.is_empty())
+                .collect();
         }
         
-        Ok(competitions)
+        config
     }
+}
 
-    fn name(&self) -> &'static str {
-        "HKUST"
+/// Extract lowercase keywords from a cleaned competition name, ignoring short words.
+/// When `stem_words` is enabled each keyword is cut back to the part it shares with its stem,
+/// so "finance" becomes "financ" and still finds "Financial" as a substring.
+fn name_keywords(name: &str, match_config: &MatchConfig) -> Vec<String> {
+    let cleaned = clean_competition_name(name, match_config).to_lowercase();
+    
+    let mut keywords: Vec<String> = cleaned
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() > 2)
+        .map(|word| {
+            if !match_config.stem_words {
+                return word.to_string();
+            }
+            // Stemming can rewrite the ending ("company" becomes "compani"), so keep only the shared prefix
+            let stem = ENGLISH_STEMMER.stem(word);
+            word.chars()
+                .zip(stem.chars())
+                .take_while(|(letter, stemmed)| letter == stemmed)
+                .map(|(letter, _)| letter)
+                .collect()
+        })
+        .collect();
+    keywords.sort();
+    keywords.dedup();
+    keywords
+}
+
+/// Build a MongoDB filter for the stored competitions that could be the same event as `competition`:
+/// those sharing at least one keyword with its name, or a variant of one that stems the same way,
+/// plus everything `is_same_event` could accept by date alone
+fn candidate_filter(competition: &Competition, match_config: &MatchConfig) -> Document {
+    let keywords = name_keywords(&competition.name, match_config);
+    if keywords.is_empty() || competition.date_unknown {
+        // Nothing to narrow on, or a placeholder date that matches any date, so every stored competition is a candidate
+        return doc! {};
+    }
+    
+    let pattern = keywords
+        .iter()
+        .map(|keyword| regex::escape(keyword))
+        .collect::<Vec<_>>()
+        .join("|");
+    // Names can fuzzy-match without sharing a keyword ("Blockchain" and "Blokchain"), so every
+    // competition inside the date window, or with a placeholder date, is fetched as well
+    doc! {
+        "$or": [
+            { "name": { "$regex": pattern, "$options": "i" } },
+            {
+                "date": {
+                    "$gte": (competition.date - match_config.date_window).to_rfc3339(),
+                    "$lte": (competition.date + match_config.date_window).to_rfc3339(),
+                }
+            },
+            { "date_unknown": true },
+        ]
     }
 }
 
-/// Check if a competition already exists in the database using fuzzy matching
-async fn is_duplicate_competition(db: &mongodb::Database, new_comp: &Competition) -> bool {
+/// Fetch stored competitions that plausibly match `competition`, to be confirmed with `fuzzy_match`
+async fn find_candidate_competitions(
+    db: &mongodb::Database,
+    competition: &Competition,
+    match_config: &MatchConfig,
+) -> Result<Vec<Competition>, mongodb::error::Error> {
     let collection: Collection<Competition> = db.collection("competitions");
+    let cursor = collection.find(candidate_filter(competition, match_config)).await?;
+    cursor.try_collect().await
+}
+
+/// Whether two competitions are the same event: the names fuzzy-match and, unless either
+/// date is a placeholder (`date_unknown`), they fall within the configured window of each other
+fn is_same_event(new_comp: &Competition, existing: &Competition, match_config: &MatchConfig) -> bool {
+    if !fuzzy_match(&new_comp.name, &existing.name, match_config) {
+        return false;
+    }
+    if new_comp.date_unknown || existing.date_unknown {
+        return true;
+    }
+    
+    (new_comp.date - existing.date).abs() <= match_config.date_window
+}
+
+/// Check if a competition already exists in the database using fuzzy matching on names and dates
+async fn is_duplicate_competition(
+    db: &mongodb::Database,
+    new_comp: &Competition,
+    match_config: &MatchConfig,
+) -> bool {
+    // Only fetch plausible candidates instead of scanning the whole collection
+    let candidates = match find_candidate_competitions(db, new_comp, match_config).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Error fetching duplicate candidates for '{}': {}", new_comp.name, e);
+            return false;
+        }
+    };
+    
+    candidates
+        .iter()
+        .any(|existing| is_same_event(new_comp, existing, match_config))
+}
+
+/// Stored competitions that fuzzy-match `competition`, excluding itself, ranked by name similarity.
+/// The score is the string similarity of the cleaned names, from 0.0 to 1.0.
+pub(crate) async fn find_similar_competitions(
+    db: &mongodb::Database,
+    competition: &Competition,
+    match_config: &MatchConfig,
+) -> Result<Vec<(Competition, f64)>, mongodb::error::Error> {
+    let cleaned = clean_competition_name(&competition.name, match_config).to_lowercase();
     
-    // Get all existing competitions
-    let cursor = collection.find(doc! {}).await.unwrap();
-    let existing_competitions: Vec<Competition> = cursor.try_collect().await.unwrap();
+    let mut similar: Vec<(Competition, f64)> = find_candidate_competitions(db, competition, match_config)
+        .await?
+        .into_iter()
+        .filter(|candidate| candidate.id != competition.id)
+        .filter(|candidate| fuzzy_match(&competition.name, &candidate.name, match_config))
+        .map(|candidate| {
+            let score = calculate_similarity(
+                &cleaned,
+                &clean_competition_name(&candidate.name, match_config).to_lowercase(),
+            );
+            (candidate, score)
+        })
+        .collect();
+    similar.sort_by(|a, b| b.1.total_cmp(&a.1));
     
-    // Simple fuzzy matching by checking if the name contains similar words
-    for existing in existing_competitions {
-        if fuzzy_match(&new_comp.name, &existing.name) {
-            return true;
+    Ok(similar)
+}
+
+/// Stored competitions the deduplicator would treat as the same event as `competition`:
+/// similar competitions whose dates also fall within the match window, best match first
+pub(crate) async fn find_duplicate_competitions(
+    db: &mongodb::Database,
+    competition: &Competition,
+    match_config: &MatchConfig,
+) -> Result<Vec<(Competition, f64)>, mongodb::error::Error> {
+    let similar = find_similar_competitions(db, competition, match_config).await?;
+    Ok(similar
+        .into_iter()
+        .filter(|(candidate, _)| is_same_event(competition, candidate, match_config))
+        .collect())
+}
+
+/// English stemmer shared by every word overlap check
+static ENGLISH_STEMMER: LazyLock<Stemmer> = LazyLock::new(|| Stemmer::create(Algorithm::English));
+
+/// Words of a lowercase name for the word overlap check, stemmed when `stem_words` is enabled
+fn overlap_words(name: &str, match_config: &MatchConfig) -> Vec<String> {
+    let words = name.split_whitespace();
+    if match_config.stem_words {
+        words.map(|word| ENGLISH_STEMMER.stem(word).into_owned()).collect()
+    } else {
+        words.map(str::to_string).collect()
+    }
+}
+
+/// Whether a name has nothing left to compare once stop words and blocked phrases are removed,
+/// like "Information Session [HKU]" or "Announcement"
+fn is_low_information_name(name: &str, match_config: &MatchConfig) -> bool {
+    let tokenize = |text: &str| -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let mut words: Vec<String> = tokenize(&clean_competition_name(name, match_config))
+        .into_iter()
+        .filter(|word| !match_config.stop_words.contains(word))
+        .collect();
+    
+    for phrase in &match_config.blocked_phrases {
+        let phrase = tokenize(phrase);
+        if phrase.is_empty() {
+            continue;
+        }
+        let mut i = 0;
+        while i < words.len() {
+            if words[i..].starts_with(&phrase) {
+                words.drain(i..i + phrase.len());
+            } else {
+                i += 1;
+            }
         }
     }
     
-    false
+    words.is_empty()
 }
 
 /// Improved fuzzy matching algorithm to check if two competition names are similar
-fn fuzzy_match(name1: &str, name2: &str) -> bool {
-    let name1_clean = clean_competition_name(name1);
-    let name2_clean = clean_competition_name(name2);
+fn fuzzy_match(name1: &str, name2: &str, match_config: &MatchConfig) -> bool {
+    // Generic titles like "Information Session" would otherwise match every other generic title
+    if is_low_information_name(name1, match_config) || is_low_information_name(name2, match_config) {
+        return false;
+    }
+    
+    let name1_clean = clean_competition_name(name1, match_config);
+    let name2_clean = clean_competition_name(name2, match_config);
     
     let name1_lower = name1_clean.to_lowercase();
     let name2_lower = name2_clean.to_lowercase();
@@ -219,22 +565,23 @@ fn fuzzy_match(name1: &str, name2: &str) -> bool {
     
     // Calculate similarity using multiple methods
     let similarity = calculate_similarity(&name1_lower, &name2_lower);
-    if similarity > 0.75 {  // Higher threshold for string similarity
+    if similarity > match_config.similarity_threshold {
         return true;
     }
     
-    // Calculate word overlap
-    let words1: Vec<&str> = name1_lower.split_whitespace().collect();
-    let words2: Vec<&str> = name2_lower.split_whitespace().collect();
+    // Calculate word overlap, on stems when enabled. Stems can still differ ("financ" and "financi"),
+    // so the partial containment check below is what lets such variants meet
+    let words1 = overlap_words(&name1_lower, match_config);
+    let words2 = overlap_words(&name2_lower, match_config);
     
     let mut common_words = 0;
     for word1 in &words1 {
         if word1.len() > 2 {  // Only consider words longer than 2 characters
-            if words2.iter().any(|&word2| {
+            if words2.iter().any(|word2| {
                 word2.len() > 2 && (  // Only consider words longer than 2 characters
-                    *word1 == word2 ||  // Exact match
+                    word1 == word2 ||  // Exact match
                     word1.contains(word2) || word2.contains(word1) ||  // Partial containment
-                    calculate_similarity(word1, word2) > 0.7  // High similarity
+                    calculate_similarity(word1, word2) > match_config.word_similarity_threshold
                 )
             }) {
                 common_words += 1;
@@ -244,91 +591,92 @@ fn fuzzy_match(name1: &str, name2: &str) -> bool {
     
     // Check if there's significant overlap
     let max_len = words1.len().max(words2.len());
-    if max_len > 0 && common_words as f32 / max_len as f32 > 0.5 {  // At least 50% overlap
+    if max_len > 0 && common_words as f64 / max_len as f64 > match_config.word_overlap_threshold {
         return true;
     }
     
     // Check if the ratio of common words to total unique words is high
-    let all_words: std::collections::HashSet<&str> = words1.iter().chain(words2.iter()).cloned().collect();
-    if !all_words.is_empty() && common_words as f32 / all_words.len() as f32 > 0.4 {
+    let all_words: HashSet<&str> = words1.iter().chain(words2.iter()).map(String::as_str).collect();
+    if !all_words.is_empty() && common_words as f64 / all_words.len() as f64 > match_config.unique_word_ratio_threshold {
         return true;
     }
     
     false
 }
 
-/// Helper function to clean competition names by removing source indicators like [HKU], [UST]
-fn clean_competition_name(name: &str) -> String {
+/// Words dropped from competition names before comparison (matched case-insensitively as whole tokens)
+const DEFAULT_STOP_WORDS: &[&str] = &[
+    // University indicators
+    "hku", "ust", "hkust", "cuhk",
+    // Articles, conjunctions and prepositions
+    "the", "a", "an", "and", "of", "in", "on", "at", "to", "for", "with", "by", "up",
+    // Generic event words
+    "competition", "case", "challenge", "hackathon", "datathon", "program", "event",
+    "session", "workshop", "seminar", "deadline", "register", "join", "now",
+];
+
+/// Generic titles that say nothing about which event they announce, so names made up only of
+/// these and stop words are never merged
+const DEFAULT_BLOCKED_PHRASES: &[&str] = &[
+    "information session", "info session", "information", "info", "briefing", "announcement",
+    "announcements", "notice", "reminder", "update", "news",
+];
+
+/// NFKC-normalize a name and replace typographic quotes and dashes with their ASCII forms,
+/// so "Founders’ Cup" and "Founders' Cup" compare equal
+fn normalize_unicode(name: &str) -> String {
+    name.nfkc()
+        .map(|c| match c {
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => '\'',
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => '"',
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Helper function to clean competition names by removing trailing source indicators like [HKU], [UST],
+/// which older documents carry in their names
+fn clean_competition_name(name: &str, match_config: &MatchConfig) -> String {
+    let normalized = normalize_unicode(name);
+    
     // Remove source indicators in brackets
     let re = regex::Regex::new(r"\s*\[.*?\]\s*$").unwrap_or_else(|_| regex::Regex::new(r"^").unwrap());
-    let cleaned = re.replace_all(name, "").trim().to_string();
-    
-    // Remove common university indicators and normalize spaces
-    
-    
-    cleaned.replace("HKU", "")
-        .replace("UST", "")
-        .replace("HKUST", "")
-        .replace("The", "")
-        .replace("the", "")
-        .replace("A", "")
-        .replace("a", "")
-        .replace("An", "")
-        .replace("an", "")
-        .replace("And", "")
-        .replace("and", "")
-        .replace("Of", "")
-        .replace("of", "")
-        .replace("In", "")
-        .replace("in", "")
-        .replace("On", "")
-        .replace("on", "")
-        .replace("At", "")
-        .replace("at", "")
-        .replace("To", "")
-        .replace("to", "")
-        .replace("For", "")
-        .replace("for", "")
-        .replace("With", "")
-        .replace("with", "")
-        .replace("By", "")
-        .replace("by", "")
-        .replace("Up", "")
-        .replace("up", "")
-        .replace("Competition", "")
-        .replace("competition", "")
-        .replace("Case", "")
-        .replace("case", "")
-        .replace("Challenge", "")
-        .replace("challenge", "")
-        .replace("Hackathon", "")
-        .replace("hackathon", "")
-        .replace("Datathon", "")
-        .replace("datathon", "")
-        .replace("Program", "")
-        .replace("program", "")
-        .replace("Event", "")
-        .replace("event", "")
-        .replace("Session", "")
-        .replace("session", "")
-        .replace("Workshop", "")
-        .replace("workshop", "")
-        .replace("Seminar", "")
-        .replace("seminar", "")
-        .replace("Deadline", "")
-        .replace("deadline", "")
-        .replace("Register", "")
-        .replace("register", "")
-        .replace("Join", "")
-        .replace("join", "")
-        .replace("NOW", "")
-        .replace("now", "")
+    let cleaned = re.replace_all(&normalized, "");
+    
+    // Drop whole-word stop words only, so words like "AI" or "Data" keep their letters.
+    // Splitting on Unicode whitespace also collapses non-breaking and repeated spaces.
+    cleaned
         .split_whitespace()
+        .filter(|token| !match_config.stop_words.contains(&token.to_lowercase()))
         .collect::<Vec<_>>()
         .join(" ")
 }
 
-/// Simple string similarity function using a basic algorithm
+/// Levenshtein edit distance between two strings, counted in characters
+fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+    let chars1: Vec<char> = s1.chars().collect();
+    let chars2: Vec<char> = s2.chars().collect();
+    
+    // Single-row dynamic programming table
+    let mut previous: Vec<usize> = (0..=chars2.len()).collect();
+    let mut current = vec![0; chars2.len() + 1];
+    
+    for (i, c1) in chars1.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, c2) in chars2.iter().enumerate() {
+            let substitution_cost = if c1 == c2 { 0 } else { 1 };
+            current[j + 1] = (previous[j] + substitution_cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    
+    previous[chars2.len()]
+}
+
+/// String similarity as a normalized Levenshtein ratio between 0.0 and 1.0
 fn calculate_similarity(s1: &str, s2: &str) -> f64 {
     let s1 = s1.trim().to_lowercase();
     let s2 = s2.trim().to_lowercase();
@@ -343,71 +691,168 @@ fn calculate_similarity(s1: &str, s2: &str) -> f64 {
         return 1.0;
     }
     
-    // Simple character-based similarity
-    let common_chars = s1.chars().filter(|c| s2.contains(*c)).count();
-    let total_chars = s1.len().max(s2.len());
-    
-    if total_chars == 0 {
-        0.0
-    } else {
-        common_chars as f64 / total_chars as f64
+    let max_len = s1.chars().count().max(s2.chars().count());
+    1.0 - levenshtein_distance(&s1, &s2) as f64 / max_len as f64
+}
+
+/// Union two comma-joined source lists, keeping first-seen order and dropping blanks and repeats
+pub(crate) fn merge_sources(existing: &str, new: &str) -> String {
+    let mut sources: Vec<&str> = Vec::new();
+    for source in existing.split(',').chain(new.split(',')).map(str::trim) {
+        if !source.is_empty() && !sources.contains(&source) {
+            sources.push(source);
+        }
     }
+    sources.join(", ")
 }
 
-/// Update existing competition's source field to include the new scraper
+/// Update the source field of the stored duplicate of `competition` to include its scraper
 async fn update_existing_competition_source(
     db: &mongodb::Database,
-    name: &str,
-    scraper_name: &str,
+    competition: &Competition,
+    match_config: &MatchConfig,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let collection: Collection<Competition> = db.collection("competitions");
     
-    // Find competitions with similar names using fuzzy matching
-    let cursor = collection.find(doc! {}).await.unwrap();
-    let existing_competitions: Vec<Competition> = cursor.try_collect().await.unwrap();
-    
-    for existing in existing_competitions {
-        // Use the clean names for fuzzy matching to ignore source indicators like [HKU], [UST]
-        if fuzzy_match(name, &existing.name) {
-            // Update the source field to include the new scraper
-            let mut sources: Vec<&str> = existing.source.split(',').map(|s| s.trim()).collect();
-            if !sources.contains(&scraper_name) {
-                sources.push(scraper_name);
-                let new_source = sources.join(", ");
-                
-                collection
-                    .update_one(
-                        doc! { "_id": existing.id.unwrap() },
-                        doc! { "$set": { "source": new_source } },
-                    )
-                    .await?;
-            }
+    // Locate the best existing match among the narrowed candidates
+    let candidates = find_candidate_competitions(db, competition, match_config).await?;
+    let Some(existing) = candidates
+        .into_iter()
+        .find(|existing| is_same_event(competition, existing, match_config))
+    else {
+        return Ok(());
+    };
+    let Some(existing_id) = existing.id else {
+        return Ok(());
+    };
+    
+    // Update the source field to include the new scraper, and adopt its stable id if the match has none
+    let new_source = merge_sources(&existing.source, &competition.source);
+    let adopt_external_id = existing.external_id.is_none() && competition.external_id.is_some();
+    if new_source != existing.source || adopt_external_id {
+        let mut update = doc! { "source": new_source, "updated_at": chrono::Utc::now().to_rfc3339() };
+        if adopt_external_id {
+            update.insert("external_id", competition.external_id.as_deref());
         }
+        collection
+            .update_one(doc! { "_id": existing_id }, doc! { "$set": update })
+            .await?;
     }
     
     Ok(())
 }
 
+/// Whether a stored competition already carries `competition`'s external id
+async fn external_id_exists(
+    db: &mongodb::Database,
+    competition: &Competition,
+) -> Result<bool, mongodb::error::Error> {
+    let Some(external_id) = &competition.external_id else {
+        return Ok(false);
+    };
+    let collection: Collection<Competition> = db.collection("competitions");
+    Ok(collection.find_one(doc! { "external_id": external_id }).await?.is_some())
+}
+
+/// Move end dates that earlier scraper versions stored as `signup_deadline` into `end_date`,
+/// returning how many competitions were fixed. Safe to run on every startup.
+pub async fn move_end_dates_out_of_deadlines(db: &mongodb::Database) -> Result<u64, mongodb::error::Error> {
+    let collection: Collection<Document> = db.collection("competitions");
+    let result = collection
+        .update_many(
+            doc! {
+                "$or": [{ "external_id": { "$regex": "^kaggle:" } }, { "source": "Unstop" }],
+                "signup_deadline": { "$type": "string" },
+            },
+            vec![
+                doc! { "$set": { "end_date": "$signup_deadline" } },
+                doc! { "$unset": "signup_deadline" },
+            ],
+        )
+        .await?;
+    Ok(result.modified_count)
+}
+
+/// Fields refreshed from a re-scraped competition when its external id matches a stored one
+const EXTERNAL_ID_REFRESHED_FIELDS: &[&str] = &[
+    "name",
+    "date",
+    "date_unknown",
+    "end_date",
+    "description",
+    "signup_deadline",
+    "location",
+    "registration_link",
+    "max_participants",
+];
+
 /// CTFTime Scraper implementation
-pub struct CtfTimeScraper;
+///
+/// Fetches upcoming events by default; set `start`/`finish` (unix timestamps) to backfill a past window.
+pub struct CtfTimeScraper {
+    pub limit: u32,
+    pub start: Option<i64>,
+    pub finish: Option<i64>,
+}
+
+impl Default for CtfTimeScraper {
+    fn default() -> Self {
+        CtfTimeScraper {
+            limit: 20,
+            start: None,
+            finish: None,
+        }
+    }
+}
+
+impl CtfTimeScraper {
+    /// Create a scraper from `CTFTIME_LIMIT`, `CTFTIME_START` and `CTFTIME_FINISH`, defaulting to upcoming events
+    pub fn from_env() -> Self {
+        let env_i64 = |key: &str| std::env::var(key).ok().and_then(|v| v.parse::<i64>().ok());
+        let limit = std::env::var("CTFTIME_LIMIT")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(CtfTimeScraper::default().limit);
+        
+        match (env_i64("CTFTIME_START"), env_i64("CTFTIME_FINISH")) {
+            (Some(start), Some(finish)) => Self::with_window(start, finish, limit),
+            _ => CtfTimeScraper {
+                limit,
+                ..CtfTimeScraper::default()
+            },
+        }
+    }
+    
+    /// Create a scraper for events within the given unix timestamp window
+    pub fn with_window(start: i64, finish: i64, limit: u32) -> Self {
+        CtfTimeScraper {
+            limit,
+            start: Some(start),
+            finish: Some(finish),
+        }
+    }
+}
 
 #[async_trait::async_trait]
 impl Scraper for CtfTimeScraper {
-    async fn scrape(&self, db: &mongodb::Database) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
-        let url = "https://ctftime.org/api/v1/events/";
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
         
-        // Create a client that can handle SSL verification with rustls
-        let client = reqwest::Client::builder()
-            .danger_accept_invalid_certs(true)
-            .use_rustls_tls()
-            .build()?;
+        // CTFTime returns upcoming events when no window is given
+        let mut query = vec![("limit", self.limit.to_string())];
+        if let Some(start) = self.start {
+            query.push(("start", start.to_string()));
+        }
+        if let Some(finish) = self.finish {
+            query.push(("finish", finish.to_string()));
+        }
         
-        // Fetch the page - get upcoming events (next 20)
+        // Fetch the events
         let response = client.get(url)
-            .header("User-Agent", "Mozilla/5.0 (compatible; CTFScraper/1.0)")
-            .query(&[("limit", "20")]) // Get up to 20 upcoming events
+            .query(&query)
             .send()
-            .await?;
+            .await?
+            .error_for_status()?;
         
         let body = response.text().await?;
         
@@ -418,7 +863,7 @@ impl Scraper for CtfTimeScraper {
         
         for event in events {
             // Extract relevant fields from the CTFTime API response
-            if let (Some(title), Some(start_time), Some(_end_time), Some(url), Some(description)) = (
+            if let (Some(title), Some(start_time), Some(end_time), Some(url), Some(description)) = (
                 event.get("title").and_then(|v| v.as_str()),
                 event.get("start").and_then(|v| v.as_str()),
                 event.get("finish").and_then(|v| v.as_str()),
@@ -426,186 +871,1831 @@ impl Scraper for CtfTimeScraper {
                 event.get("description").and_then(|v| v.as_str()).or(Some(""))
             ) {
                 // Parse the start time
-                let start_date = chrono::DateTime::parse_from_rfc3339(start_time)
-                    .map(|dt| dt.with_timezone(&chrono::Utc))
-                    .unwrap_or_else(|_| chrono::Utc::now());
+                let start_date = dateparse::parse_date(start_time);
+                
+                // Events that already finished are recorded as completed
+                let finished = dateparse::parse_date(end_time).is_some_and(|end| end < chrono::Utc::now());
                 
                 // Create competition with CTFTime source
                 let competition = Competition {
-                    id: None, // Will be set by MongoDB
-                    name: format!("{title} [CTF]"),
-                    date: start_date,
-                    host: "CTFTime".to_string(),
-                    source: "CTFTime".to_string(),
                     description: if description.is_empty() { None } else { Some(description.to_string()) },
-                    signup_deadline: None, // CTFTime API doesn't always provide registration deadline
                     location: Some("Online".to_string()), // Most CTFs are online
                     registration_link: if url.is_empty() { None } else { Some(url.to_string()) },
                     max_participants: event.get("max_team_size")
                         .and_then(|v| v.as_i64())
                         .map(|v| v as i32),
-                    status: Some("upcoming".to_string()),
+                    status: Some(if finished { "completed" } else { "upcoming" }.to_string()),
+                    tags: vec!["ctf".to_string()],
+                    external_id: event.get("id").and_then(|v| v.as_i64()).map(|id| format!("ctftime:{id}")),
+                    ..Competition::scraped(title, "CTFTime", "CTFTime", start_date)
                 };
                 
-                // Use fuzzy matching to check for duplicates
-                if !is_duplicate_competition(db, &competition).await {
-                    competitions.push(competition);
-                } else {
-                    // If it's a duplicate, update the source field to include CTFTime
-                    update_existing_competition_source(db, &competition.name, "CTFTime").await?;
-                }
+                competitions.push(competition);
             }
         }
         
         Ok(competitions)
     }
 
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "CTFTime"
     }
-}
 
-/// ScraperManager to manage multiple scrapers
-pub struct ScraperManager {
-    scrapers: HashMap<String, Box<dyn Scraper>>,
+    fn source_url(&self) -> &str {
+        "https://ctftime.org/api/v1/events/"
+    }
 }
 
-impl ScraperManager {
-    pub fn new() -> Self {
-        let mut manager = ScraperManager {
-            scrapers: HashMap::new(),
-        };
-        
-        // Register default scrapers
-        manager.register_scraper(Box::new(HkuScraper));
-        manager.register_scraper(Box::new(HkustScraper));
-        manager.register_scraper(Box::new(CtfTimeScraper));
+/// CUHK Business School Scraper implementation
+pub struct CuhkScraper;
 
-        manager
+#[async_trait::async_trait]
+impl Scraper for CuhkScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Fetch the page
+        let response = client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+        
+        // Parse HTML and extract titles synchronously to avoid Send issues
+        let titles = {
+            // Parse HTML
+            let document = Html::parse_document(&body);
+            
+            // Create selector for event listing items
+            let item_selector = parse_selector("div.event-item")?;
+            let title_selector = parse_selector(".event-item__title")?;
+            
+            // Collect titles
+            document
+                .select(&item_selector)
+                .filter_map(|item| {
+                    item.select(&title_selector).next()
+                })
+                .map(|title_element| {
+                    title_element.text().collect::<Vec<_>>().join(" ").trim().to_string()
+                })
+                .filter(|title| !title.is_empty())
+                .collect::<Vec<String>>()
+        }; // HTML document is dropped here, so no Send issues
+        
+        let mut competitions = Vec::new();
+        
+        // Process each title
+        for title in titles {
+            // Create competition with CUHK source
+            let competition = Competition {
+                status: Some("upcoming".to_string()),
+                tags: vec!["business".to_string()],
+                ..Competition::scraped(title, "CUHK", "CUHK", None)
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
     }
-    
-    pub fn register_scraper(&mut self, scraper: Box<dyn Scraper>) {
-        self.scrapers.insert(scraper.name().to_lowercase(), scraper);
+
+    fn name(&self) -> &str {
+        "CUHK"
     }
-    
-    pub fn get_scraper_names(&self) -> Vec<String> {
-        self.scrapers.keys().cloned().collect()
+
+    fn source_url(&self) -> &str {
+        "https://www.bschool.cuhk.edu.hk/events/"
     }
-    
-    pub async fn run_scraper(
+}
+
+/// Major League Hacking season Scraper implementation
+pub struct MlhScraper;
+
+/// Event details extracted from an MLH event card
+struct MlhEvent {
+    name: String,
+    start: Option<chrono::NaiveDate>,
+    end: Option<chrono::NaiveDate>,
+    location: Option<String>,
+    link: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl Scraper for MlhScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Fetch the page
+        let response = client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+        
+        // Parse HTML and extract event details synchronously to avoid Send issues
+        let events = {
+            // Parse HTML
+            let document = Html::parse_document(&body);
+            
+            // Create selectors for event cards and their fields
+            let event_selector = parse_selector("div.event")?;
+            let name_selector = parse_selector("h3.event-name")?;
+            let start_selector = parse_selector("meta[itemprop='startDate']")?;
+            let end_selector = parse_selector("meta[itemprop='endDate']")?;
+            let city_selector = parse_selector("[itemprop='city']")?;
+            let state_selector = parse_selector("[itemprop='state']")?;
+            let link_selector = parse_selector("a.event-link")?;
+            
+            // The displayed date range is mirrored in ISO form by the startDate/endDate meta tags
+            let parse_meta_date = |element: scraper::ElementRef| {
+                element
+                    .value()
+                    .attr("content")
+                    .and_then(|content| chrono::NaiveDate::parse_from_str(content.trim(), "%Y-%m-%d").ok())
+            };
+            let element_text = |element: scraper::ElementRef| {
+                element.text().collect::<Vec<_>>().join(" ").trim().to_string()
+            };
+            
+            document
+                .select(&event_selector)
+                .filter_map(|event| {
+                    let name = event.select(&name_selector).next().map(element_text)?;
+                    
+                    // Combine city and state into a single location string
+                    let location_parts: Vec<String> = event
+                        .select(&city_selector)
+                        .chain(event.select(&state_selector))
+                        .map(element_text)
+                        .filter(|part| !part.is_empty())
+                        .collect();
+                    
+                    Some(MlhEvent {
+                        name,
+                        start: event.select(&start_selector).next().and_then(parse_meta_date),
+                        end: event.select(&end_selector).next().and_then(parse_meta_date),
+                        location: if location_parts.is_empty() { None } else { Some(location_parts.join(", ")) },
+                        link: event
+                            .select(&link_selector)
+                            .next()
+                            .and_then(|link| link.value().attr("href"))
+                            .map(|href| href.to_string()),
+                    })
+                })
+                .collect::<Vec<MlhEvent>>()
+        }; // HTML document is dropped here, so no Send issues
+        
+        let now = chrono::Utc::now();
+        let mut competitions = Vec::new();
+        
+        for event in events {
+            let Some(start) = event.start else {
+                continue;
+            };
+            let start_date = start.and_hms_opt(0, 0, 0).unwrap().and_utc();
+            let end_date = event.end.unwrap_or(start).and_hms_opt(23, 59, 59).unwrap().and_utc();
+            
+            // The season page also lists past events, so only keep ones that haven't ended
+            if end_date < now {
+                continue;
+            }
+            
+            // Create competition with MLH source
+            let competition = Competition {
+                location: event.location,
+                registration_link: event.link,
+                status: Some(if start_date > now { "upcoming" } else { "active" }.to_string()),
+                tags: vec!["hackathon".to_string()],
+                ..Competition::scraped(event.name, "MLH", "MLH", Some(start_date))
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        "MLH"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://mlh.io/seasons/2025/events"
+    }
+}
+
+/// Kaggle competitions Scraper implementation
+///
+/// Uses the Kaggle API when `KAGGLE_USERNAME`/`KAGGLE_KEY` are set, otherwise scrapes the public listing page.
+pub struct KaggleScraper {
+    credentials: Option<(String, String)>,
+}
+
+impl KaggleScraper {
+    /// Create a scraper using API credentials from the environment, if present
+    pub fn from_env() -> Self {
+        let credentials = match (std::env::var("KAGGLE_USERNAME"), std::env::var("KAGGLE_KEY")) {
+            (Ok(username), Ok(key)) if !username.is_empty() && !key.is_empty() => Some((username, key)),
+            _ => None,
+        };
+        
+        KaggleScraper { credentials }
+    }
+    
+    /// Fetch active competitions from the authenticated Kaggle API
+    async fn fetch_from_api(
+        &self,
+        client: &reqwest::Client,
+        username: &str,
+        key: &str,
+    ) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = "https://www.kaggle.com/api/v1/competitions/list";
+        
+        let response = client.get(url)
+            .basic_auth(username, Some(key))
+            .send()
+            .await?
+            .error_for_status()?;
+        
+        let body = response.text().await?;
+        
+        // Parse JSON response from Kaggle API
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&body)?;
+        
+        let competitions = entries
+            .iter()
+            .filter_map(|entry| {
+                let title = entry.get("title").and_then(|v| v.as_str())?;
+                let parse_date = |field: &str| {
+                    entry.get(field)
+                        .and_then(|v| v.as_str())
+                        .and_then(dateparse::parse_date)
+                };
+                
+                // `ref` is either the full URL or just the competition slug
+                let link = entry.get("url")
+                    .or_else(|| entry.get("ref"))
+                    .and_then(|v| v.as_str())
+                    .map(|r| if r.starts_with("http") { r.to_string() } else { format!("https://www.kaggle.com/competitions/{r}") });
+                
+                Some(Self::build_competition(
+                    title,
+                    entry.get("description").and_then(|v| v.as_str()),
+                    parse_date("enabledDate"),
+                    // The final submission deadline, when the competition ends, not a signup deadline
+                    parse_date("deadline"),
+                    link,
+                    entry.get("maxTeamSize").and_then(|v| v.as_i64()).map(|v| v as i32),
+                ))
+            })
+            .collect();
+        
+        Ok(competitions)
+    }
+    
+    /// Fetch competitions by scraping the public Kaggle listing page
+    async fn fetch_from_public_page(
+        &self,
+        client: &reqwest::Client,
+    ) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Fetch the page
+        let response = client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+        
+        // Parse HTML and extract titles and links synchronously to avoid Send issues
+        let entries = {
+            // Parse HTML
+            let document = Html::parse_document(&body);
+            
+            // Competition cards link to /competitions/<slug>
+            let link_selector = parse_selector("a[href^='/competitions/']")?;
+            
+            let mut entries: Vec<(String, String)> = Vec::new();
+            for link in document.select(&link_selector) {
+                let title = link.text().collect::<Vec<_>>().join(" ").trim().to_string();
+                let href = link.value().attr("href").unwrap_or_default();
+                
+                if !title.is_empty() && !entries.iter().any(|(_, existing)| existing == href) {
+                    entries.push((title, href.to_string()));
+                }
+            }
+            
+            entries
+        }; // HTML document is dropped here, so no Send issues
+        
+        let competitions = entries
+            .into_iter()
+            .map(|(title, href)| {
+                Self::build_competition(&title, None, None, None, Some(format!("https://www.kaggle.com{href}")), None)
+            })
+            .collect();
+        
+        Ok(competitions)
+    }
+    
+    /// Competition slug from a Kaggle link such as `https://www.kaggle.com/competitions/titanic/overview`
+    fn slug_from_link(link: &str) -> Option<&str> {
+        let (_, path) = link.split_once("/competitions/")?;
+        path.split(['/', '?', '#']).next().filter(|slug| !slug.is_empty())
+    }
+    
+    /// Build a Kaggle competition record from the extracted fields
+    fn build_competition(
+        title: &str,
+        description: Option<&str>,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        link: Option<String>,
+        max_team_size: Option<i32>,
+    ) -> Competition {
+        Competition {
+            description: description.filter(|d| !d.is_empty()).map(|d| d.to_string()),
+            end_date: end,
+            location: Some("Online".to_string()),
+            max_participants: max_team_size,
+            status: Some("active".to_string()),
+            tags: vec!["data-science".to_string()],
+            external_id: link.as_deref().and_then(Self::slug_from_link).map(|slug| format!("kaggle:{slug}")),
+            registration_link: link,
+            ..Competition::scraped(title, "Kaggle", "Kaggle", start)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Scraper for KaggleScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let scraped = match &self.credentials {
+            Some((username, key)) => self.fetch_from_api(client, username, key).await?,
+            None => self.fetch_from_public_page(client).await?,
+        };
+        
+        Ok(scraped)
+    }
+
+    fn name(&self) -> &str {
+        "Kaggle"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://www.kaggle.com/competitions"
+    }
+}
+
+/// Envelope wrapping every Codeforces API response
+#[derive(Debug, Deserialize)]
+struct CodeforcesResponse<T> {
+    status: String,
+    #[serde(default)]
+    comment: Option<String>,
+    result: Option<T>,
+}
+
+/// Contest entry from the Codeforces `contest.list` method
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CodeforcesContest {
+    id: i64,
+    name: String,
+    phase: String,
+    #[serde(default)]
+    start_time_seconds: Option<i64>,
+}
+
+/// Codeforces contest Scraper implementation
+pub struct CodeforcesScraper;
+
+#[async_trait::async_trait]
+impl Scraper for CodeforcesScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Gym contests are training mirrors rather than rated rounds, so leave them out
+        let response = client.get(url)
+            .query(&[("gym", "false")])
+            .send()
+            .await?
+            .error_for_status()?;
+        
+        let body = response.text().await?;
+        
+        // Parse the {status, result} envelope from the Codeforces API
+        let envelope: CodeforcesResponse<Vec<CodeforcesContest>> = serde_json::from_str(&body)?;
+        if envelope.status != "OK" {
+            return Err(format!(
+                "Codeforces API returned {}: {}",
+                envelope.status,
+                envelope.comment.unwrap_or_default()
+            )
+            .into());
+        }
+        
+        let mut competitions = Vec::new();
+        
+        // Only contests that have not started yet
+        for contest in envelope.result.unwrap_or_default() {
+            if contest.phase != "BEFORE" {
+                continue;
+            }
+            let Some(start_date) = contest
+                .start_time_seconds
+                .and_then(|seconds| chrono::DateTime::from_timestamp(seconds, 0))
+            else {
+                continue;
+            };
+            
+            // Create competition with Codeforces source
+            let competition = Competition {
+                location: Some("Online".to_string()),
+                registration_link: Some(format!("https://codeforces.com/contests/{}", contest.id)),
+                status: Some("upcoming".to_string()),
+                tags: vec!["competitive-programming".to_string()],
+                external_id: Some(format!("codeforces:{}", contest.id)),
+                ..Competition::scraped(contest.name, "Codeforces", "Codeforces", Some(start_date))
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        "Codeforces"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://codeforces.com/api/contest.list"
+    }
+}
+
+/// Contest row extracted from the AtCoder upcoming contests table
+struct AtCoderContest {
+    name: String,
+    start: Option<chrono::DateTime<chrono::Utc>>,
+    duration: Option<String>,
+    link: Option<String>,
+}
+
+/// AtCoder upcoming contests Scraper implementation
+pub struct AtCoderScraper;
+
+#[async_trait::async_trait]
+impl Scraper for AtCoderScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Fetch the page
+        let response = client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+        
+        // Parse HTML and extract contest rows synchronously to avoid Send issues
+        let contests = {
+            // Parse HTML
+            let document = Html::parse_document(&body);
+            
+            // Upcoming contests table columns: start time, contest name, duration, rated range
+            let row_selector = parse_selector("#contest-table-upcoming tbody tr")?;
+            let cell_selector = parse_selector("td")?;
+            let time_selector = parse_selector("time")?;
+            let link_selector = parse_selector("a[href^='/contests/']")?;
+            
+            let element_text = |element: scraper::ElementRef| {
+                element.text().collect::<Vec<_>>().join(" ").trim().to_string()
+            };
+            
+            document
+                .select(&row_selector)
+                .filter_map(|row| {
+                    let cells: Vec<_> = row.select(&cell_selector).collect();
+                    let name_cell = cells.get(1)?;
+                    let link = name_cell.select(&link_selector).next()?;
+                    
+                    Some(AtCoderContest {
+                        name: element_text(link),
+                        start: cells
+                            .first()
+                            .and_then(|cell| cell.select(&time_selector).next())
+                            .and_then(|time| dateparse::parse_with_tz(&element_text(time), dateparse::JST)),
+                        duration: cells.get(2).map(|cell| element_text(*cell)).filter(|d| !d.is_empty()),
+                        link: link.value().attr("href").and_then(|href| absolutize_link(url, href)),
+                    })
+                })
+                .filter(|contest| !contest.name.is_empty())
+                .collect::<Vec<AtCoderContest>>()
+        }; // HTML document is dropped here, so no Send issues
+        
+        let now = chrono::Utc::now();
+        let mut competitions = Vec::new();
+        
+        for contest in contests {
+            // Only keep contests that have not started yet
+            let Some(start_date) = contest.start.filter(|start| *start > now) else {
+                continue;
+            };
+            
+            // Create competition with AtCoder source
+            let competition = Competition {
+                description: contest.duration.map(|duration| format!("Duration: {duration}")),
+                location: Some("Online".to_string()),
+                registration_link: contest.link,
+                status: Some("upcoming".to_string()),
+                tags: vec!["competitive-programming".to_string()],
+                ..Competition::scraped(contest.name, "AtCoder", "AtCoder", Some(start_date))
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        "AtCoder"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://atcoder.jp/contests/?lang=en"
+    }
+}
+
+/// Response body of the HackerEarth events feed
+#[derive(Debug, Deserialize)]
+struct HackerEarthResponse {
+    #[serde(default)]
+    response: Vec<HackerEarthEvent>,
+}
+
+/// Challenge entry from the HackerEarth events feed
+#[derive(Debug, Deserialize)]
+struct HackerEarthEvent {
+    title: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    challenge_type: Option<String>,
+    #[serde(default)]
+    start_utc_tz: Option<String>,
+    #[serde(default)]
+    end_utc_tz: Option<String>,
+    #[serde(default)]
+    location: Option<String>,
+}
+
+/// Tag for a HackerEarth challenge type
+fn hackerearth_tag(challenge_type: &str) -> Option<&'static str> {
+    match challenge_type.trim().to_lowercase().as_str() {
+        "hackathon" => Some("hackathon"),
+        "hiring" => Some("hiring"),
+        "competitive" => Some("competitive-programming"),
+        _ => None,
+    }
+}
+
+/// HackerEarth challenges Scraper implementation
+pub struct HackerEarthScraper;
+
+#[async_trait::async_trait]
+impl Scraper for HackerEarthScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Fetch the events feed
+        let response = client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+        
+        // Parse JSON response from the HackerEarth events feed
+        let events: HackerEarthResponse = serde_json::from_str(&body)?;
+        
+        let now = chrono::Utc::now();
+        let mut competitions = Vec::new();
+        
+        for event in events.response {
+            let Some(start_date) = event.start_utc_tz.as_deref().and_then(dateparse::parse_date) else {
+                continue;
+            };
+            
+            // Skip challenges that have already ended
+            let end_date = event.end_utc_tz.as_deref().and_then(dateparse::parse_date);
+            if end_date.is_some_and(|end| end < now) {
+                continue;
+            }
+            
+            let tags = event
+                .challenge_type
+                .as_deref()
+                .and_then(hackerearth_tag)
+                .map(|tag| vec![tag.to_string()])
+                .unwrap_or_default();
+            
+            // Create competition with HackerEarth source
+            let competition = Competition {
+                description: event.description.filter(|d| !d.trim().is_empty()),
+                // Challenges without a venue are run online
+                location: Some(
+                    event
+                        .location
+                        .filter(|location| !location.trim().is_empty())
+                        .unwrap_or_else(|| "Online".to_string()),
+                ),
+                registration_link: event.url.filter(|link| !link.is_empty()),
+                status: Some(if start_date > now { "upcoming" } else { "active" }.to_string()),
+                tags,
+                ..Competition::scraped(event.title.trim(), "HackerEarth", "HackerEarth", Some(start_date))
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        "HackerEarth"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://www.hackerearth.com/chrome-extension/events/"
+    }
+}
+
+/// Response body of the Unstop opportunity search
+#[derive(Debug, Deserialize)]
+struct UnstopResponse {
+    data: UnstopPage,
+}
+
+/// One page of Unstop search results
+#[derive(Debug, Deserialize)]
+struct UnstopPage {
+    #[serde(default)]
+    data: Vec<UnstopOpportunity>,
+    #[serde(default)]
+    last_page: Option<u32>,
+}
+
+/// Organisation hosting an Unstop opportunity
+#[derive(Debug, Deserialize)]
+struct UnstopOrganisation {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// Competition entry from the Unstop opportunity search
+#[derive(Debug, Deserialize)]
+struct UnstopOpportunity {
+    title: String,
+    #[serde(default)]
+    public_url: Option<String>,
+    #[serde(default)]
+    start_date: Option<String>,
+    #[serde(default)]
+    end_date: Option<String>,
+    #[serde(default)]
+    region: Option<String>,
+    #[serde(default)]
+    organisation: Option<UnstopOrganisation>,
+}
+
+/// Unstop (formerly Dare2Compete) competitions Scraper implementation
+///
+/// Reads the first `pages` pages of open competitions from the public search API.
+pub struct UnstopScraper {
+    pub pages: u32,
+}
+
+impl Default for UnstopScraper {
+    fn default() -> Self {
+        UnstopScraper { pages: 3 }
+    }
+}
+
+impl UnstopScraper {
+    /// Create a scraper reading `UNSTOP_MAX_PAGES` pages, defaulting to 3
+    pub fn from_env() -> Self {
+        let pages = std::env::var("UNSTOP_MAX_PAGES")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|pages| *pages > 0)
+            .unwrap_or(UnstopScraper::default().pages);
+        
+        UnstopScraper { pages }
+    }
+}
+
+#[async_trait::async_trait]
+impl Scraper for UnstopScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        let mut opportunities = Vec::new();
+        
+        // Page through open competitions until the last page or the configured page limit
+        for page in 1..=self.pages {
+            let response = client.get(url)
+                .query(&[
+                    ("opportunity", "competitions"),
+                    ("oppstatus", "open"),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await?
+                .error_for_status()?;
+            
+            let body = response.text().await?;
+            let results: UnstopResponse = serde_json::from_str(&body)?;
+            
+            let exhausted = results.data.data.is_empty()
+                || results.data.last_page.is_some_and(|last_page| page >= last_page);
+            opportunities.extend(results.data.data);
+            if exhausted {
+                break;
+            }
+        }
+        
+        let now = chrono::Utc::now();
+        let mut competitions = Vec::new();
+        
+        for opportunity in opportunities {
+            let end_date = opportunity.end_date.as_deref().and_then(dateparse::parse_date);
+            let start_date = opportunity.start_date.as_deref().and_then(dateparse::parse_date);
+            
+            // Listings without a start date are dated by their end date
+            let Some(date) = start_date.or(end_date) else {
+                continue;
+            };
+            
+            let host = opportunity
+                .organisation
+                .and_then(|organisation| organisation.name)
+                .filter(|name| !name.trim().is_empty())
+                .unwrap_or_else(|| "Unstop".to_string());
+            let location = opportunity.region.map(|region| match region.to_lowercase().as_str() {
+                "online" => "Online".to_string(),
+                _ => region,
+            });
+            
+            // Create competition with Unstop source
+            let competition = Competition {
+                end_date,
+                location,
+                registration_link: opportunity
+                    .public_url
+                    .and_then(|path| absolutize_link("https://unstop.com/", &path)),
+                status: Some(if date > now { "upcoming" } else { "active" }.to_string()),
+                ..Competition::scraped(opportunity.title.trim(), host, "Unstop", Some(date))
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        "Unstop"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://unstop.com/api/public/opportunity/search-result"
+    }
+}
+
+/// GraphQL query for LeetCode's upcoming weekly and biweekly contests
+const LEETCODE_UPCOMING_QUERY: &str = "{ upcomingContests { title titleSlug startTime duration } }";
+
+/// Response body of the LeetCode GraphQL endpoint
+#[derive(Debug, Deserialize)]
+struct LeetCodeResponse {
+    data: Option<LeetCodeData>,
+    #[serde(default)]
+    errors: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeetCodeData {
+    #[serde(default)]
+    upcoming_contests: Vec<LeetCodeContest>,
+}
+
+/// Contest entry from the LeetCode `upcomingContests` query
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeetCodeContest {
+    title: String,
+    title_slug: String,
+    /// Unix timestamp in seconds
+    start_time: i64,
+    /// Length in seconds
+    #[serde(default)]
+    duration: Option<i64>,
+}
+
+/// LeetCode weekly and biweekly contest Scraper implementation
+pub struct LeetCodeScraper;
+
+#[async_trait::async_trait]
+impl Scraper for LeetCodeScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Query the GraphQL endpoint for upcoming contests
+        let body = serde_json::to_string(&serde_json::json!({ "query": LEETCODE_UPCOMING_QUERY }))?;
+        let response = client.post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(reqwest::header::REFERER, "https://leetcode.com/contest/")
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?;
+        
+        let body = response.text().await?;
+        
+        // Parse the {data: {...}} GraphQL response
+        let response: LeetCodeResponse = serde_json::from_str(&body)?;
+        let Some(data) = response.data else {
+            return Err(format!("LeetCode GraphQL query failed: {:?}", response.errors).into());
+        };
+        
+        let mut competitions = Vec::new();
+        
+        for contest in data.upcoming_contests {
+            let Some(start_date) = chrono::DateTime::from_timestamp(contest.start_time, 0) else {
+                continue;
+            };
+            
+            // Create competition with LeetCode source
+            let competition = Competition {
+                description: contest
+                    .duration
+                    .map(|seconds| format!("Duration: {} minutes", seconds / 60)),
+                location: Some("Online".to_string()),
+                registration_link: Some(format!("https://leetcode.com/contest/{}/", contest.title_slug)),
+                status: Some("upcoming".to_string()),
+                tags: vec!["competitive-programming".to_string()],
+                ..Competition::scraped(contest.title, "LeetCode", "LeetCode", Some(start_date))
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        "LeetCode"
+    }
+
+    fn source_url(&self) -> &str {
+        "https://leetcode.com/graphql"
+    }
+}
+
+/// Listing page definition for a `ConfigurableScraper`, as written in `scrapers.json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfigurableScraperConfig {
+    pub name: String,
+    pub url: String,
+    pub card_selector: String,
+    pub title_selector: String,
+    #[serde(default)]
+    pub date_selector: Option<String>,
+    /// Only keep titles containing one of these keywords (case insensitive); empty keeps every card
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Tags applied to every competition from this listing
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Scraper for a listing page described by CSS selectors instead of a dedicated implementation
+pub struct ConfigurableScraper {
+    config: ConfigurableScraperConfig,
+    card_selector: Selector,
+    title_selector: Selector,
+    date_selector: Option<Selector>,
+}
+
+impl ConfigurableScraper {
+    /// Build a scraper from its config, rejecting selectors that fail to parse
+    pub fn new(mut config: ConfigurableScraperConfig) -> Result<Self, String> {
+        let parse = |field: &str, selector: &str| {
+            Selector::parse(selector)
+                .map_err(|e| format!("invalid {field} '{selector}' for scraper '{}': {e:?}", config.name))
+        };
+        
+        let card_selector = parse("card_selector", &config.card_selector)?;
+        let title_selector = parse("title_selector", &config.title_selector)?;
+        let date_selector = config
+            .date_selector
+            .as_deref()
+            .map(|selector| parse("date_selector", selector))
+            .transpose()?;
+        
+        config.tags = config
+            .tags
+            .iter()
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        
+        Ok(ConfigurableScraper {
+            config,
+            card_selector,
+            title_selector,
+            date_selector,
+        })
+    }
+    
+    fn matches_keywords(&self, title: &str) -> bool {
+        self.config.keywords.is_empty() || title_matches_keywords(title, &self.config.keywords)
+    }
+}
+
+/// Load configurable scrapers from a JSON array of `ConfigurableScraperConfig`.
+///
+/// A missing file yields no scrapers. Unreadable files and invalid entries are logged and
+/// skipped so one bad definition does not stop the rest from loading.
+pub fn load_configurable_scrapers(path: &str) -> Vec<ConfigurableScraper> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            tracing::info!("No scraper config found at {}, skipping configurable scrapers", path);
+            return Vec::new();
+        }
+        Err(e) => {
+            tracing::error!("Failed to read scraper config {}: {}", path, e);
+            return Vec::new();
+        }
+    };
+    
+    let configs: Vec<ConfigurableScraperConfig> = match serde_json::from_str(&contents) {
+        Ok(configs) => configs,
+        Err(e) => {
+            tracing::error!("Failed to parse scraper config {}: {}", path, e);
+            return Vec::new();
+        }
+    };
+    
+    configs
+        .into_iter()
+        .filter_map(|config| match ConfigurableScraper::new(config) {
+            Ok(scraper) => Some(scraper),
+            Err(e) => {
+                tracing::error!("Skipping scraper from {}: {}", path, e);
+                None
+            }
+        })
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl Scraper for ConfigurableScraper {
+    async fn scrape(&self, client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = self.source_url();
+        
+        // Fetch the page
+        let response = client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+        
+        // Parse HTML and extract titles and dates synchronously to avoid Send issues
+        let cards = {
+            let document = Html::parse_document(&body);
+            
+            document
+                .select(&self.card_selector)
+                .filter_map(|card| {
+                    let title = card
+                        .select(&self.title_selector)
+                        .next()?
+                        .text()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                        .trim()
+                        .to_string();
+                    let date = self
+                        .date_selector
+                        .as_ref()
+                        .and_then(|selector| card.select(selector).next())
+                        .and_then(|element| dateparse::parse_date_range(&element.text().collect::<String>()))
+                        .map(|(start, _)| start);
+                    Some((title, date))
+                })
+                .filter(|(title, _)| !title.is_empty() && self.matches_keywords(title))
+                .collect::<Vec<_>>()
+        }; // HTML document is dropped here, so no Send issues
+        
+        let name = self.name();
+        let mut competitions = Vec::new();
+        
+        for (title, date) in cards {
+            let competition = Competition {
+                status: Some("upcoming".to_string()),
+                tags: self.config.tags.clone(),
+                ..Competition::scraped(title, name, name, date)
+            };
+            
+            competitions.push(competition);
+        }
+        
+        Ok(competitions)
+    }
+
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn source_url(&self) -> &str {
+        &self.config.url
+    }
+}
+
+/// User-Agent sent by every scraper unless `SCRAPER_USER_AGENT` overrides it
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "Mozilla/5.0 (compatible; CompetitionScraper/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// Settings for the HTTP client shared by all scrapers
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    pub timeout: Duration,
+    /// Skip certificate validation for every scraper
+    pub accept_invalid_certs: bool,
+    /// Lowercase names of scrapers that skip certificate validation, for sites serving broken chains
+    pub insecure_scrapers: HashSet<String>,
+    pub user_agent: String,
+    /// Proxy for all scraper requests; without it the standard proxy variables are honored
+    pub proxy: Option<String>,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        HttpClientConfig {
+            timeout: Duration::from_secs(15),
+            accept_invalid_certs: false,
+            insecure_scrapers: HashSet::new(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+        }
+    }
+}
+
+impl HttpClientConfig {
+    /// Build the config from environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        let mut config = HttpClientConfig::default();
+        
+        if let Some(secs) = std::env::var("SCRAPER_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+        {
+            config.timeout = Duration::from_secs(secs);
+        }
+        if let Some(user_agent) = std::env::var("SCRAPER_USER_AGENT")
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+        {
+            config.user_agent = user_agent;
+        }
+        // `true` skips validation for every scraper, otherwise a comma-separated list of scraper names
+        if let Ok(value) = std::env::var("SCRAPER_ACCEPT_INVALID_CERTS") {
+            match value.trim().to_lowercase().as_str() {
+                "1" | "true" | "yes" => config.accept_invalid_certs = true,
+                "" | "0" | "false" | "no" => {}
+                names => {
+                    config.insecure_scrapers = names
+                        .split(',')
+                        .map(|name| name.trim().to_string())
+                        .filter(|name| !name.is_empty())
+                        .collect();
+                }
+            }
+        }
+        config.proxy = std::env::var("SCRAPER_PROXY")
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+        
+        config
+    }
+    
+    /// The proxy requests go through, from `SCRAPER_PROXY` or else the standard proxy variables,
+    /// with any password masked so it can be logged
+    pub fn proxy_summary(&self) -> Option<String> {
+        let proxy = self.proxy.clone().or_else(|| {
+            ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy", "ALL_PROXY", "all_proxy"]
+                .iter()
+                .find_map(|key| std::env::var(key).ok().filter(|v| !v.trim().is_empty()))
+        })?;
+        
+        match reqwest::Url::parse(&proxy) {
+            Ok(mut url) => {
+                if url.password().is_some() {
+                    let _ = url.set_password(Some("***"));
+                }
+                Some(url.to_string())
+            }
+            Err(_) => Some(proxy),
+        }
+    }
+    
+    /// Build a reqwest client using rustls with these settings.
+    ///
+    /// `SCRAPER_PROXY` is used for every scheme, still skipping the hosts in `NO_PROXY`. Without it
+    /// reqwest reads `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` itself. HTTPS is tunnelled through
+    /// the proxy, so certificate checks apply to the target site as usual.
+    pub fn build_client(&self) -> Result<reqwest::Client, reqwest::Error> {
+        self.build_client_with(self.accept_invalid_certs)
+    }
+    
+    /// Build a client with these settings, choosing whether certificates are validated
+    fn build_client_with(&self, accept_invalid_certs: bool) -> Result<reqwest::Client, reqwest::Error> {
+        let mut builder = reqwest::Client::builder()
+            .danger_accept_invalid_certs(accept_invalid_certs)
+            .timeout(self.timeout)
+            .user_agent(&self.user_agent)
+            .use_rustls_tls();
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?.no_proxy(reqwest::NoProxy::from_env()));
+        }
+        builder.build()
+    }
+}
+
+/// Result of running a single scraper through the ScraperManager
+pub struct ScraperOutcome {
+    pub name: String,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub result: Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>>,
+}
+
+/// ScraperManager to manage multiple scrapers
+pub struct ScraperManager {
+    scrapers: HashMap<String, Box<dyn Scraper>>,
+    client: reqwest::Client,
+    /// Client that skips certificate validation, used only by the scrapers in `insecure_scrapers`
+    insecure_client: Option<reqwest::Client>,
+    insecure_scrapers: HashSet<String>,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    cooldown: Duration,
+    last_runs: Mutex<HashMap<String, Instant>>,
+    /// Lowercase names of scrapers skipped by `run_all_scrapers`
+    disabled: Mutex<HashSet<String>>,
+    match_config: MatchConfig,
+    /// Limits how many scrapers fetch at once
+    run_permits: tokio::sync::Semaphore,
+    /// Upper bound of the random delay before each scraper fetches
+    max_jitter: Duration,
+}
+
+/// Scrapers allowed to fetch at the same time unless configured otherwise
+const DEFAULT_SCRAPER_CONCURRENCY: usize = 3;
+
+/// A random delay between zero and `max`, so concurrent scrapers do not fire in lockstep
+fn random_jitter(max: Duration) -> Duration {
+    use std::hash::{BuildHasher, Hasher};
+    
+    let max_ms = max.as_millis() as u64;
+    if max_ms == 0 {
+        return Duration::ZERO;
+    }
+    // RandomState is seeded randomly, which is plenty for spreading requests out
+    let random = std::collections::hash_map::RandomState::new().build_hasher().finish();
+    Duration::from_millis(random % (max_ms + 1))
+}
+
+/// Whether a scraper error is worth retrying (timeouts, connection failures and 5xx responses)
+fn is_transient_error(error: &(dyn std::error::Error + Send + Sync + 'static)) -> bool {
+    match error.downcast_ref::<reqwest::Error>() {
+        Some(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status().is_some_and(|status| status.is_server_error())
+        }
+        None => false,
+    }
+}
+
+impl Default for ScraperManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScraperManager {
+    pub fn new() -> Self {
+        Self::with_http_config(HttpClientConfig::default())
+            .expect("default HTTP client configuration should always build")
+    }
+    
+    pub fn with_http_config(config: HttpClientConfig) -> Result<Self, reqwest::Error> {
+        let mut manager = ScraperManager {
+            scrapers: HashMap::new(),
+            client: config.build_client()?,
+            insecure_client: if config.insecure_scrapers.is_empty() || config.accept_invalid_certs {
+                None
+            } else {
+                Some(config.build_client_with(true)?)
+            },
+            insecure_scrapers: config.insecure_scrapers.iter().map(|name| name.to_lowercase()).collect(),
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(500),
+            cooldown: Duration::from_secs(60),
+            last_runs: Mutex::new(HashMap::new()),
+            disabled: Mutex::new(HashSet::new()),
+            match_config: MatchConfig::default(),
+            run_permits: tokio::sync::Semaphore::new(DEFAULT_SCRAPER_CONCURRENCY),
+            max_jitter: Duration::from_millis(1000),
+        };
+        
+        // Register default scrapers
+        manager.register_scraper(Box::new(HkuScraper));
+        manager.register_scraper(Box::new(HkustScraper));
+        manager.register_scraper(Box::new(CtfTimeScraper::from_env()));
+        manager.register_scraper(Box::new(CuhkScraper));
+        manager.register_scraper(Box::new(MlhScraper));
+        manager.register_scraper(Box::new(KaggleScraper::from_env()));
+        manager.register_scraper(Box::new(CodeforcesScraper));
+        manager.register_scraper(Box::new(AtCoderScraper));
+        manager.register_scraper(Box::new(HackerEarthScraper));
+        manager.register_scraper(Box::new(UnstopScraper::from_env()));
+        manager.register_scraper(Box::new(LeetCodeScraper));
+
+        Ok(manager)
+    }
+    
+    /// Set how many times transient failures are retried and the initial backoff delay
+    pub fn set_retry_policy(&mut self, max_retries: u32, base_delay: Duration) {
+        self.max_retries = max_retries;
+        self.retry_base_delay = base_delay;
+    }
+    
+    /// Set the thresholds and stop words used to detect duplicate competitions
+    pub fn set_match_config(&mut self, match_config: MatchConfig) {
+        self.match_config = match_config;
+    }
+    
+    /// Set the minimum interval between on-demand runs of the same scraper
+    pub fn set_cooldown(&mut self, cooldown: Duration) {
+        self.cooldown = cooldown;
+    }
+    
+    /// Set how many scrapers may fetch at the same time (at least one)
+    pub fn set_concurrency(&mut self, max_concurrent: usize) {
+        self.run_permits = tokio::sync::Semaphore::new(max_concurrent.max(1));
+    }
+    
+    /// Set the upper bound of the random delay each scraper waits before fetching; zero disables it
+    pub fn set_jitter(&mut self, max_jitter: Duration) {
+        self.max_jitter = max_jitter;
+    }
+    
+    /// Reserve an on-demand run of the named scrapers, enforcing the per-scraper cooldown.
+    ///
+    /// Either every scraper is marked as run now, or none are and the longest remaining
+    /// wait is returned. Scheduled jobs call the run methods directly and bypass this check.
+    pub fn claim_runs(&self, names: &[String]) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut last_runs = self.last_runs.lock().unwrap_or_else(|e| e.into_inner());
+        
+        let wait = names
+            .iter()
+            .filter_map(|name| last_runs.get(&name.to_lowercase()))
+            .filter_map(|last_run| (*last_run + self.cooldown).checked_duration_since(now))
+            .max();
+        if let Some(wait) = wait.filter(|wait| !wait.is_zero()) {
+            return Err(wait);
+        }
+        
+        for name in names {
+            last_runs.insert(name.to_lowercase(), now);
+        }
+        Ok(())
+    }
+    
+    /// Run a scraper, retrying transient failures with exponential backoff.
+    ///
+    /// Waits for one of the concurrency permits, held until the scraper finishes, and then
+    /// a random jitter before the first fetch.
+    async fn scrape_with_retry(
+        &self,
+        scraper: &dyn Scraper,
+    ) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        // The semaphore is never closed, so acquiring only waits
+        let _permit = self.run_permits.acquire().await.ok();
+        tokio::time::sleep(random_jitter(self.max_jitter)).await;
+        
+        let mut attempt = 0;
+        
+        loop {
+            match scraper.scrape(self.client_for(scraper.name())).await {
+                Ok(competitions) => return Ok(competitions),
+                Err(e) if attempt < self.max_retries && is_transient_error(e.as_ref()) => {
+                    let delay = self.retry_base_delay * 2u32.pow(attempt);
+                    attempt += 1;
+                    tracing::warn!(
+                        "Scraper {} failed with a transient error: {}. Retrying in {:?} (attempt {}/{})",
+                        scraper.name(),
+                        e,
+                        delay,
+                        attempt,
+                        self.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
+    /// Fail a scrape that found nothing when the scraper's last recorded non-empty run found competitions.
+    ///
+    /// The count comes from `scraper_runs`, so the check survives restarts. Runs failed by this check
+    /// record nothing scraped, so every run keeps reporting the drift until the scraper is fixed or
+    /// the page fills up again.
+    async fn check_not_empty(
+        db: &mongodb::Database,
+        scraper: &dyn Scraper,
+        competitions: Vec<Competition>,
+    ) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+        if !competitions.is_empty() {
+            return Ok(competitions);
+        }
+        
+        let last_run = db
+            .collection::<ScraperRun>("scraper_runs")
+            .find_one(doc! { "scraper": scraper.name().to_lowercase(), "scraped": { "$gt": 0 } })
+            .sort(doc! { "started_at": -1 })
+            .await;
+        match last_run {
+            Ok(Some(run)) => {
+                tracing::warn!(
+                    "Scraper {} found no competitions at {} after finding {} on its last run",
+                    scraper.name(),
+                    scraper.source_url(),
+                    run.scraped
+                );
+                Err(Box::new(ScrapeError::EmptyResult {
+                    scraper: scraper.name().to_string(),
+                    previous: run.scraped as usize,
+                }))
+            }
+            Ok(None) => Ok(competitions),
+            Err(e) => {
+                // Without the history there is nothing to compare against, so let the empty run through
+                tracing::warn!("Failed to read the last run of scraper {}: {}", scraper.name(), e);
+                Ok(competitions)
+            }
+        }
+    }
+    
+    /// The thresholds and stop words used to detect duplicate competitions
+    pub fn match_config(&self) -> &MatchConfig {
+        &self.match_config
+    }
+    
+    /// The HTTP client shared by all scrapers, for reuse by other outgoing requests
+    pub fn client(&self) -> reqwest::Client {
+        self.client.clone()
+    }
+    
+    /// The client the named scraper fetches with: the one skipping certificate validation
+    /// when the scraper is listed in `SCRAPER_ACCEPT_INVALID_CERTS`, otherwise the shared one
+    fn client_for(&self, name: &str) -> &reqwest::Client {
+        match &self.insecure_client {
+            Some(insecure) if self.insecure_scrapers.contains(&name.to_lowercase()) => insecure,
+            _ => &self.client,
+        }
+    }
+    
+    pub fn register_scraper(&mut self, scraper: Box<dyn Scraper>) {
+        let name = scraper.name().to_lowercase();
+        if self.scrapers.contains_key(&name) {
+            tracing::warn!("Scraper {} is already registered, replacing it", name);
+        }
+        self.scrapers.insert(name, scraper);
+    }
+    
+    /// Unregister every scraper not named in `allowed` (case insensitive), returning the
+    /// allowed names that matched no registered scraper
+    pub fn retain_scrapers(&mut self, allowed: &[String]) -> Vec<String> {
+        let allowed: HashSet<String> = allowed.iter().map(|name| name.to_lowercase()).collect();
+        self.scrapers.retain(|name, _| allowed.contains(name));
+        
+        allowed
+            .into_iter()
+            .filter(|name| !self.scrapers.contains_key(name))
+            .collect()
+    }
+    
+    pub fn get_scraper_names(&self) -> Vec<String> {
+        self.scrapers.keys().cloned().collect()
+    }
+    
+    /// Names of the scrapers `run_all_scrapers` will run
+    pub fn get_enabled_scraper_names(&self) -> Vec<String> {
+        self.scrapers
+            .keys()
+            .filter(|name| self.is_enabled(name))
+            .cloned()
+            .collect()
+    }
+    
+    pub fn get_scraper_info(&self) -> Vec<ScraperInfo> {
+        let mut info: Vec<ScraperInfo> = self
+            .scrapers
+            .iter()
+            .map(|(name, scraper)| ScraperInfo {
+                name: name.clone(),
+                source_url: scraper.source_url().to_string(),
+                enabled: self.is_enabled(name),
+            })
+            .collect();
+        info.sort_by(|a, b| a.name.cmp(&b.name));
+        info
+    }
+    
+    /// Probe every scraper's source URL concurrently, sorted by scraper name.
+    ///
+    /// Sends a HEAD request, falling back to GET when the source does not allow HEAD. A source
+    /// is reachable when it responds within the timeout with anything other than a server error.
+    pub async fn check_reachability(&self) -> Vec<ScraperReachability> {
+        let probes = self.scrapers.iter().map(|(name, scraper)| async move {
+            let started = Instant::now();
+            let url = scraper.source_url();
+            
+            let client = self.client_for(name);
+            let mut response = client.head(url).timeout(REACHABILITY_TIMEOUT).send().await;
+            if let Ok(head) = &response
+                && matches!(
+                    head.status(),
+                    reqwest::StatusCode::METHOD_NOT_ALLOWED | reqwest::StatusCode::NOT_IMPLEMENTED
+                )
+            {
+                response = client.get(url).timeout(REACHABILITY_TIMEOUT).send().await;
+            }
+            
+            let status = match response {
+                Ok(response) => Some(response.status()),
+                Err(e) => {
+                    tracing::warn!("Scraper {} source {} is unreachable: {}", name, url, e);
+                    None
+                }
+            };
+            ScraperReachability {
+                name: name.clone(),
+                reachable: status.is_some_and(|status| !status.is_server_error()),
+                status_code: status.map(|status| status.as_u16()),
+                latency_ms: started.elapsed().as_millis() as u64,
+            }
+        });
+        
+        let mut results = futures_util::future::join_all(probes).await;
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        results
+    }
+    
+    fn disabled(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.disabled.lock().unwrap_or_else(|e| e.into_inner())
+    }
+    
+    /// Whether the named scraper is included in `run_all_scrapers`
+    pub fn is_enabled(&self, name: &str) -> bool {
+        !self.disabled().contains(&name.to_lowercase())
+    }
+    
+    /// Include or skip the named scraper in `run_all_scrapers`
+    pub fn set_enabled(&self, name: &str, enabled: bool) {
+        let name = name.to_lowercase();
+        if enabled {
+            self.disabled().remove(&name);
+        } else {
+            self.disabled().insert(name);
+        }
+    }
+    
+    /// Restore the enabled state of each scraper saved in the `scraper_config` collection
+    pub async fn load_settings(&self, db: &mongodb::Database) -> Result<(), mongodb::error::Error> {
+        let collection: Collection<ScraperSetting> = db.collection("scraper_config");
+        let settings: Vec<ScraperSetting> = collection.find(doc! {}).await?.try_collect().await?;
+        
+        for setting in settings {
+            self.set_enabled(&setting.name, setting.enabled);
+        }
+        Ok(())
+    }
+    
+    /// Drop competitions that fuzzy-match a stored one, merging the scraper's source into the match instead.
+    /// Returns the competitions left to save and how many were merged.
+    pub async fn filter_new_competitions(
         &self,
-        name: &str,
         db: &mongodb::Database,
+        competitions: Vec<Competition>,
+    ) -> Result<(Vec<Competition>, usize), Box<dyn std::error::Error + Send + Sync>> {
+        let mut new_competitions = Vec::new();
+        let mut merged = 0;
+        
+        for competition in competitions {
+            // A known external id is upserted when saving, so fuzzy matching is skipped
+            if external_id_exists(db, &competition).await?
+                || !is_duplicate_competition(db, &competition, &self.match_config).await
+            {
+                new_competitions.push(competition);
+            } else {
+                update_existing_competition_source(db, &competition, &self.match_config).await?;
+                merged += 1;
+            }
+        }
+        
+        Ok((new_competitions, merged))
+    }
+    
+    /// Run a scraper and return everything it found, without touching the database
+    pub async fn preview_scraper(
+        &self,
+        name: &str,
     ) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
         if let Some(scraper) = self.scrapers.get(&name.to_lowercase()) {
-            scraper.scrape(db).await
+            self.scrape_with_retry(scraper.as_ref()).await
         } else {
             Err("Scraper not found".into())
         }
     }
     
-    pub async fn run_all_scrapers(
-        &self,
-        db: &mongodb::Database,
-    ) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
-        let mut all_competitions = Vec::new();
+    pub fn has_scraper(&self, name: &str) -> bool {
+        self.scrapers.contains_key(&name.to_lowercase())
+    }
+    
+    /// Run the named scrapers concurrently, returning everything each one found in name order.
+    /// Unknown names are skipped, so check them with `unknown_scrapers` first. A scraper that finds
+    /// nothing after its last recorded run found competitions fails with `ScrapeError::EmptyResult`.
+    ///
+    /// Nothing is deduplicated against the database here: callers filter and save each outcome
+    /// in turn, so an event reported by two sources in the same run is merged rather than inserted twice.
+    pub async fn run_scrapers(&self, db: &mongodb::Database, names: &[String]) -> Vec<ScraperOutcome> {
+        let mut names: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+        names.sort();
+        names.dedup();
         
-        for scraper in self.scrapers.values() {
-            match scraper.scrape(db).await {
-                Ok(mut competitions) => {
-                    all_competitions.append(&mut competitions);
-                }
-                Err(e) => {
-                    eprintln!("Error running scraper {}: {}", scraper.name(), e);
+        let runs = names.into_iter().filter_map(|name| {
+            let scraper = self.scrapers.get(&name)?;
+            Some(async move {
+                let started_at = chrono::Utc::now();
+                let result = match self.scrape_with_retry(scraper.as_ref()).await {
+                    Ok(competitions) => Self::check_not_empty(db, scraper.as_ref(), competitions).await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = &result {
+                    tracing::error!("Error running scraper {} ({}): {}", scraper.name(), scraper.source_url(), e);
                 }
-            }
-        }
+                ScraperOutcome { name, started_at, result }
+            })
+        });
         
-        Ok(all_competitions)
+        futures_util::future::join_all(runs).await
+    }
+    
+    /// Names from `names` that match no registered scraper
+    pub fn unknown_scrapers(&self, names: &[String]) -> Vec<String> {
+        names
+            .iter()
+            .filter(|name| !self.has_scraper(name))
+            .cloned()
+            .collect()
     }
-}
-
-// Use AppState directly instead of creating a separate ScraperState
-// The scraper manager will be initialized in main and passed appropriately
-
-/// Get a new scraper manager instance (in a real app, this would be shared)
-fn get_scraper_manager() -> ScraperManager {
-    ScraperManager::new()
 }
 
 /// Handler to list all available scrapers
 pub async fn list_scrapers(
-    State(_state): State<AppState>,
-) -> Result<Json<ApiResponse<Vec<String>>>, StatusCode> {
-    let manager = get_scraper_manager();
-    let scraper_names = manager.get_scraper_names();
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<ScraperInfo>>>, AppError> {
+    let scrapers = state.scrapers.get_scraper_info();
     
     Ok(Json(ApiResponse {
         success: true,
-        data: Some(scraper_names),
+        data: Some(scrapers),
         message: Some("Available scrapers retrieved successfully".to_string()),
     }))
 }
 
-/// Handler to run all scrapers
-pub async fn run_all_scrapers(
-    State(state): State<AppState>,
-) -> Result<Json<ApiResponse<String>>, StatusCode> {
-    let manager = get_scraper_manager();
-    let competitions = match manager.run_all_scrapers(&state.db).await {
-        Ok(comps) => comps,
-        Err(e) => {
-            eprintln!("Error running all scrapers: {e}");
-            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+/// Outcome of a single scraper run, including what it contributed to the database
+#[derive(Debug, Serialize)]
+pub struct ScraperRunReport {
+    pub scraper: String,
+    pub scraped: usize,
+    pub inserted: usize,
+    pub merged: usize,
+    pub inserted_ids: Vec<String>,
+    /// Competitions that could not be inserted, as "name: reason"
+    pub insert_errors: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl ScraperRunReport {
+    fn new(scraper: String) -> Self {
+        ScraperRunReport {
+            scraper,
+            scraped: 0,
+            inserted: 0,
+            merged: 0,
+            inserted_ids: Vec::new(),
+            insert_errors: Vec::new(),
+            error: None,
         }
-    };
-    
-    // Save the scraped competitions to the database
-    let collection: Collection<Competition> = state.db.collection("competitions");
-    let competitions_count = competitions.len();
+    }
+}
+
+/// Persist scraped competitions, merging sources into existing ones and batch-inserting the rest
+async fn save_scraped_competitions(
+    collection: &Collection<Competition>,
+    competitions: Vec<Competition>,
+    report: &mut ScraperRunReport,
+    notifier: &Notifier,
+) -> Result<(), mongodb::error::Error> {
+    let mut new_competitions: Vec<Competition> = Vec::new();
     
     for mut competition in competitions {
+        // A stable source id identifies the same event across runs, so refresh it in place
+        if let Some(external_id) = competition.external_id.clone()
+            && let Some(existing_comp) = collection.find_one(doc! { "external_id": &external_id }).await?
+        {
+            let mut update: Document = mongodb::bson::to_document(&competition)?
+                .into_iter()
+                .filter(|(key, _)| EXTERNAL_ID_REFRESHED_FIELDS.contains(&key.as_str()))
+                .collect();
+            update.insert("source", merge_sources(&existing_comp.source, &competition.source));
+            update.insert("updated_at", chrono::Utc::now().to_rfc3339());
+            let mut refresh = doc! { "$set": update };
+            if !competition.date_unknown {
+                // A false flag is not serialized, so clear a stored placeholder explicitly
+                refresh.insert("$unset", doc! { "date_unknown": "" });
+            }
+            collection
+                .update_one(doc! { "_id": existing_comp.id }, refresh)
+                .await?;
+            report.merged += 1;
+            continue;
+        }
+        
         // Check if the competition already exists
         let existing = collection
             .find_one(
                 doc! { "name": &competition.name },
             )
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            .await?;
         
         if let Some(existing_comp) = existing {
             // Update the source field to include both sources
-            let mut sources: Vec<&str> = existing_comp.source.split(',').map(|s| s.trim()).collect();
-            let new_sources: Vec<&str> = competition.source.split(',').map(|s| s.trim()).collect();
-            
-            for new_source in new_sources {
-                if !sources.contains(&new_source) {
-                    sources.push(new_source);
-                }
-            }
-            
-            let updated_source = sources.join(", ");
+            let updated_source = merge_sources(&existing_comp.source, &competition.source);
             collection
                 .update_one(
                     doc! { "_id": existing_comp.id.unwrap() },
-                    doc! { "$set": { "source": updated_source } },
+                    doc! { "$set": { "source": updated_source, "updated_at": chrono::Utc::now().to_rfc3339() } },
                 )
-                .await
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                .await?;
+            report.merged += 1;
+        } else if let Some(pending) = new_competitions.iter_mut().find(|c| {
+            c.name == competition.name || (c.external_id.is_some() && c.external_id == competition.external_id)
+        }) {
+            // Same event scraped twice in this batch, so fold it into the pending insert
+            pending.source = merge_sources(&pending.source, &competition.source);
+            report.merged += 1;
         } else {
-            // Insert new competition
-            competition.id = None; // Let MongoDB generate the ID
-            collection
-                .insert_one(competition)
-                .await
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            // Known up front, since a partially failed insert does not report which ids were stored
+            competition.id = Some(ObjectId::new());
+            competition.mark_created();
+            new_competitions.push(competition);
+        }
+    }
+    
+    if new_competitions.is_empty() {
+        return Ok(());
+    }
+    
+    // Insert all new competitions in a single round-trip. Unordered, so a unique-index collision
+    // only fails its own competition rather than the rest of the batch
+    assign_slugs(collection, &mut new_competitions).await?;
+    let failed = match collection.insert_many(&new_competitions).ordered(false).await {
+        Ok(_) => HashMap::new(),
+        Err(e) => insert_write_errors(&e).ok_or(e)?,
+    };
+    
+    let mut inserted = Vec::with_capacity(new_competitions.len());
+    for (position, competition) in new_competitions.into_iter().enumerate() {
+        match failed.get(&position) {
+            Some(error) => {
+                tracing::warn!("Failed to insert scraped competition '{}': {}", competition.name, error);
+                report.insert_errors.push(format!("{}: {}", competition.name, error));
+            }
+            None => {
+                report.inserted_ids.extend(competition.id.map(|id| id.to_hex()));
+                inserted.push(competition);
+            }
+        }
+    }
+    report.inserted += inserted.len();
+    notifier.notify_new_competitions(&inserted);
+    
+    Ok(())
+}
+
+/// Deduplicate everything one scraper found against the database and save the rest, counting
+/// fuzzy merges as well as the exact-name and external id merges made while saving
+async fn deduplicate_and_save(
+    state: &AppState,
+    competitions: Vec<Competition>,
+    report: &mut ScraperRunReport,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    report.scraped = competitions.len();
+    let (new_competitions, merged) = state.scrapers.filter_new_competitions(&state.db, competitions).await?;
+    report.merged += merged;
+    
+    let collection: Collection<Competition> = state.db.collection("competitions");
+    save_scraped_competitions(&collection, new_competitions, report, &state.notifier).await?;
+    Ok(())
+}
+
+/// Optional body for `POST /scrapers/run` naming the scrapers to run
+#[derive(Debug, Default, Deserialize)]
+pub struct RunScrapersRequest {
+    #[serde(default)]
+    pub scrapers: Vec<String>,
+}
+
+/// Handler to run the scrapers named in the body concurrently, or every enabled scraper
+/// when the body is absent or names none
+pub async fn run_all_scrapers(
+    State(state): State<AppState>,
+    body: axum::body::Bytes,
+) -> Result<Json<ApiResponse<Vec<ScraperRunReport>>>, AppError> {
+    let request: RunScrapersRequest = if body.iter().all(u8::is_ascii_whitespace) {
+        RunScrapersRequest::default()
+    } else {
+        serde_json::from_slice(&body)
+            .map_err(|e| AppError::BadRequest(format!("Invalid request body: {e}")))?
+    };
+    
+    let names = if request.scrapers.is_empty() {
+        state.scrapers.get_enabled_scraper_names()
+    } else {
+        let unknown = state.scrapers.unknown_scrapers(&request.scrapers);
+        if !unknown.is_empty() {
+            return Err(AppError::BadRequest(format!("Unknown scrapers: {}", unknown.join(", "))));
+        }
+        request.scrapers
+    };
+    
+    state
+        .scrapers
+        .claim_runs(&names)
+        .map_err(AppError::RateLimited)?;
+    
+    let outcomes = state.scrapers.run_scrapers(&state.db, &names).await;
+    
+    // Deduplicate and save one scraper at a time, so each sees what the previous ones inserted
+    let mut reports = Vec::new();
+    
+    for ScraperOutcome { name: scraper, started_at, result } in outcomes {
+        let mut report = ScraperRunReport::new(scraper);
+        // A failed save is reported like a failed scrape, so later scrapers still run and are recorded
+        match result {
+            Ok(competitions) => {
+                if let Err(e) = deduplicate_and_save(&state, competitions, &mut report).await {
+                    tracing::error!("Failed to save competitions scraped by {}: {}", report.scraper, e);
+                    report.error = Some(format!("Failed to save scraped competitions: {e}"));
+                }
+            }
+            Err(e) => {
+                report.error = Some(e.to_string());
+            }
         }
+        
+        record_scraper_run(&state.db, &report, started_at).await;
+        state.metrics.record_scrape(&report.scraper, report.inserted, report.merged, report.error.is_some());
+        reports.push(report);
     }
     
+    let failed = reports.iter().filter(|report| report.error.is_some()).count();
+    if failed > 0 && failed == reports.len() {
+        let errors: Vec<String> = reports
+            .iter()
+            .filter_map(|report| report.error.as_ref().map(|error| format!("{}: {}", report.scraper, error)))
+            .collect();
+        return Err(AppError::scraper("Every scraper failed", errors.join("; ")));
+    }
+    let message = if failed == 0 {
+        "All scrapers ran successfully".to_string()
+    } else {
+        format!("{failed} of {} scrapers failed", reports.len())
+    };
+    
     Ok(Json(ApiResponse {
         success: true,
-        data: Some(format!("Successfully scraped {} competitions from {} scrapers", competitions_count, manager.get_scraper_names().len())),
-        message: Some("All scrapers ran successfully".to_string()),
+        data: Some(reports),
+        message: Some(message),
     }))
 }
 
@@ -613,68 +2703,524 @@ pub async fn run_all_scrapers(
 pub async fn run_specific_scraper(
     State(state): State<AppState>,
     Path(name): Path<String>,
-) -> Result<Json<ApiResponse<String>>, StatusCode> {
-    let manager = get_scraper_manager();
-    let competitions = match manager.run_scraper(&name, &state.db).await {
+) -> Result<Json<ApiResponse<ScraperRunReport>>, AppError> {
+    if !state.scrapers.has_scraper(&name) {
+        return Err(AppError::NotFound);
+    }
+    
+    state
+        .scrapers
+        .claim_runs(std::slice::from_ref(&name))
+        .map_err(AppError::RateLimited)?;
+    
+    let Some(ScraperOutcome { name: scraper, started_at, result }) = state
+        .scrapers
+        .run_scrapers(&state.db, std::slice::from_ref(&name))
+        .await
+        .pop()
+    else {
+        return Err(AppError::NotFound);
+    };
+    let mut report = ScraperRunReport::new(scraper);
+    
+    let competitions = match result {
         Ok(comps) => comps,
-        Err(_) => {
-            return Err(StatusCode::NOT_FOUND);
+        Err(e) => {
+            report.error = Some(e.to_string());
+            record_scraper_run(&state.db, &report, started_at).await;
+            state.metrics.record_scrape(&report.scraper, 0, 0, true);
+            return Err(AppError::scraper(&format!("Scraper '{name}' failed"), e));
         }
     };
     
     // Save the scraped competitions to the database
-    let collection: Collection<Competition> = state.db.collection("competitions");
-    let competitions_count = competitions.len();
+    deduplicate_and_save(&state, competitions, &mut report)
+        .await
+        .map_err(|e| AppError::database("Failed to save scraped competitions", e))?;
+    record_scraper_run(&state.db, &report, started_at).await;
+    state.metrics.record_scrape(&report.scraper, report.inserted, report.merged, false);
     
-    for mut competition in competitions {
-        // Check if the competition already exists
-        let existing = collection
-            .find_one(
-                doc! { "name": &competition.name },
-            )
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        
-        if let Some(existing_comp) = existing {
-            // Update the source field to include both sources
-            let mut sources: Vec<&str> = existing_comp.source.split(',').map(|s| s.trim()).collect();
-            let new_sources: Vec<&str> = competition.source.split(',').map(|s| s.trim()).collect();
-            
-            for new_source in new_sources {
-                if !sources.contains(&new_source) {
-                    sources.push(new_source);
-                }
-            }
-            
-            let updated_source = sources.join(", ");
-            collection
-                .update_one(
-                    doc! { "_id": existing_comp.id.unwrap() },
-                    doc! { "$set": { "source": updated_source } },
-                )
-                .await
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        } else {
-            // Insert new competition
-            competition.id = None; // Let MongoDB generate the ID
-            collection
-                .insert_one(competition)
-                .await
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        }
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(report),
+        message: Some(format!("Scraper '{name}' ran successfully")),
+    }))
+}
+
+/// Handler to run a scraper without saving anything, returning the raw competitions it found
+pub async fn preview_scraper(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<ApiResponse<Vec<Competition>>>, AppError> {
+    if !state.scrapers.has_scraper(&name) {
+        return Err(AppError::NotFound);
     }
     
+    state
+        .scrapers
+        .claim_runs(std::slice::from_ref(&name))
+        .map_err(AppError::RateLimited)?;
+    
+    let competitions = state
+        .scrapers
+        .preview_scraper(&name)
+        .await
+        .map_err(|e| AppError::scraper(&format!("Scraper '{name}' failed"), e))?;
+    
     Ok(Json(ApiResponse {
         success: true,
-        data: Some(format!("Successfully scraped {competitions_count} competitions from {name}")),
-        message: Some(format!("Scraper '{name}' ran successfully")),
+        message: Some(format!("Scraper '{name}' found {} competitions (not saved)", competitions.len())),
+        data: Some(competitions),
+    }))
+}
+
+/// Handler to enable or disable a scraper for `run_all_scrapers`, persisting the choice
+pub async fn toggle_scraper(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<ApiResponse<ScraperInfo>>, AppError> {
+    if !state.scrapers.has_scraper(&name) {
+        return Err(AppError::NotFound);
+    }
+    
+    let name = name.to_lowercase();
+    let enabled = !state.scrapers.is_enabled(&name);
+    
+    let collection: Collection<ScraperSetting> = state.db.collection("scraper_config");
+    collection
+        .replace_one(
+            doc! { "_id": &name },
+            ScraperSetting { name: name.clone(), enabled },
+        )
+        .upsert(true)
+        .await
+        .map_err(|e| AppError::database("Failed to save scraper setting", e))?;
+    state.scrapers.set_enabled(&name, enabled);
+    
+    let info = state
+        .scrapers
+        .get_scraper_info()
+        .into_iter()
+        .find(|info| info.name == name)
+        .ok_or(AppError::NotFound)?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        message: Some(format!(
+            "Scraper '{name}' {}",
+            if enabled { "enabled" } else { "disabled" }
+        )),
+        data: Some(info),
+    }))
+}
+
+/// Index on the scraper_runs collection for finding each scraper's latest runs
+pub fn scraper_run_indexes() -> Vec<IndexModel> {
+    vec![IndexModel::builder()
+        .keys(doc! { "scraper": 1, "started_at": -1 })
+        .options(
+            IndexOptions::builder()
+                .name("scraper_1_started_at_-1".to_string())
+                .build(),
+        )
+        .build()]
+}
+
+/// Write a scraper run record to the `scraper_runs` collection, logging rather than failing on errors
+async fn record_scraper_run(
+    db: &mongodb::Database,
+    report: &ScraperRunReport,
+    started_at: chrono::DateTime<chrono::Utc>,
+) {
+    let run = ScraperRun {
+        id: None,
+        scraper: report.scraper.clone(),
+        started_at,
+        finished_at: chrono::Utc::now(),
+        scraped: report.scraped as u32,
+        inserted: report.inserted as u32,
+        merged: report.merged as u32,
+        error: report.error.clone(),
+    };
+    
+    let collection: Collection<ScraperRun> = db.collection("scraper_runs");
+    if let Err(e) = collection.insert_one(run).await {
+        tracing::error!("Failed to record run for scraper {}: {}", report.scraper, e);
+    }
+}
+
+/// Handler to get the most recent run of each scraper
+pub async fn get_scraper_runs(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<ScraperRun>>>, AppError> {
+    let collection: Collection<ScraperRun> = state.db.collection("scraper_runs");
+    
+    // RFC3339 UTC timestamps sort chronologically as strings
+    let pipeline = vec![
+        doc! { "$sort": { "started_at": -1 } },
+        doc! { "$group": { "_id": "$scraper", "run": { "$first": "$$ROOT" } } },
+        doc! { "$replaceRoot": { "newRoot": "$run" } },
+        doc! { "$sort": { "scraper": 1 } },
+    ];
+    
+    let documents: Vec<Document> = collection
+        .aggregate(pipeline)
+        .await
+        .map_err(|e| AppError::database("Error aggregating scraper runs", e))?
+        .try_collect()
+        .await
+        .map_err(|e| AppError::database("Error reading scraper runs", e))?;
+    
+    let runs = documents
+        .into_iter()
+        .map(mongodb::bson::from_document::<ScraperRun>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::database("Error decoding scraper run", e))?;
+    
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(runs),
+        message: Some("Latest scraper runs retrieved successfully".to_string()),
     }))
 }
 
-/// Create the router for scraper routes
+/// Create the router for scraper routes. Triggering scrapers requires an API key
 pub fn create_scraper_router() -> Router<AppState> {
-    Router::new()
-        .route("/", get(list_scrapers))
+    let protected = Router::new()
         .route("/run", post(run_all_scrapers))
         .route("/:name", post(run_specific_scraper))
+        .route("/:name/preview", post(preview_scraper))
+        .route("/:name/toggle", post(toggle_scraper))
+        .route_layer(middleware::from_fn(require_api_key));
+    
+    Router::new()
+        .route("/", get(list_scrapers))
+        .route("/runs", get(get_scraper_runs))
+        .merge(protected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_competition_name_keeps_meaningful_tokens() {
+        let config = MatchConfig::default();
+        
+        assert_eq!(clean_competition_name("AI Analytics Challenge", &config), "AI Analytics");
+        assert_eq!(clean_competition_name("Data Science Marathon", &config), "Data Science Marathon");
+        assert_eq!(clean_competition_name("AI Analytics Challenge [HKU]", &config), "AI Analytics");
+    }
+
+    #[test]
+    fn similarity_separates_typos_from_different_names() {
+        let threshold = MatchConfig::default().similarity_threshold;
+        
+        assert_eq!(levenshtein_distance("hackathon", "hackathonn"), 1);
+        assert!(calculate_similarity("hackathon", "hackathonn") > threshold);
+        assert!(calculate_similarity("finance case", "robotics expo") < threshold);
+    }
+
+    #[test]
+    fn merge_sources_joins_without_repeats() {
+        assert_eq!(merge_sources("HKU", "CUHK"), "HKU, CUHK");
+        assert_eq!(merge_sources("HKU, CUHK", "HKU"), "HKU, CUHK");
+    }
+
+    #[test]
+    fn merge_sources_handles_duplicates_whitespace_and_empty_existing() {
+        assert_eq!(merge_sources("HKU, HKU", "HKU"), "HKU");
+        assert_eq!(merge_sources("  HKU ,CUHK  ", " CUHK , UST"), "HKU, CUHK, UST");
+        assert_eq!(merge_sources("", "HKU"), "HKU");
+        assert_eq!(merge_sources(" , ", "HKU"), "HKU");
+    }
+
+    #[test]
+    fn keyword_filter_keeps_competitions_and_drops_other_events() {
+        assert!(title_matches_keywords("Global Case Competition 2024", COMPETITION_KEYWORDS));
+        assert!(title_matches_keywords("HKU DATATHON", COMPETITION_KEYWORDS));
+        assert!(title_matches_keywords("fintech hackathon", COMPETITION_KEYWORDS));
+        assert!(!title_matches_keywords("Information Session", COMPETITION_KEYWORDS));
+        assert!(!title_matches_keywords("Career Fair 2024", COMPETITION_KEYWORDS));
+    }
+
+    #[test]
+    fn typographic_variants_are_duplicates() {
+        let config = MatchConfig::default();
+        let pairs = [
+            ("Founders\u{2019} Cup", "Founders' Cup"),
+            ("Data\u{2013}Driven Finance Cup", "Data-Driven Finance Cup"),
+            ("Quant\u{00A0}Trading Cup", "Quant Trading Cup"),
+        ];
+        
+        for (typographic, plain) in pairs {
+            assert_eq!(normalize_unicode(typographic), plain);
+            assert!(fuzzy_match(typographic, plain, &config), "{typographic} vs {plain}");
+        }
+    }
+
+    #[test]
+    fn absolutize_link_resolves_relative_hrefs() {
+        let base = "https://example.edu/events/list.html";
+        
+        assert_eq!(absolutize_link(base, "/apply").as_deref(), Some("https://example.edu/apply"));
+        assert_eq!(absolutize_link(base, "detail.html").as_deref(), Some("https://example.edu/events/detail.html"));
+        assert_eq!(absolutize_link(base, "../news").as_deref(), Some("https://example.edu/news"));
+        assert_eq!(
+            absolutize_link(base, "https://other.org/form").as_deref(),
+            Some("https://other.org/form")
+        );
+        assert_eq!(absolutize_link(base, "javascript:void(0)"), None);
+        assert_eq!(absolutize_link(base, "#top"), None);
+        assert_eq!(absolutize_link(base, "  "), None);
+    }
+
+    #[test]
+    fn hkust_rows_parse_dates_in_hong_kong_time() {
+        let body = include_str!("../tests/fixtures/hkust_announcements.html");
+        let before = chrono::Utc::now();
+        let competitions = parse_hkust_announcements(body, "https://bmundergrad.hkust.edu.hk/announcement")
+            .expect("valid selectors");
+        
+        assert_eq!(competitions.len(), 2);
+        
+        let case = &competitions[0];
+        assert_eq!(case.name, "HKUST Business Case Competition 2024");
+        // Midnight in Hong Kong is 16:00 UTC the day before
+        assert_eq!(case.date.to_rfc3339(), "2024-03-14T16:00:00+00:00");
+        assert!(!case.date_unknown);
+        assert_eq!(
+            case.registration_link.as_deref(),
+            Some("https://bmundergrad.hkust.edu.hk/announcement/business-case-competition-2024")
+        );
+        
+        // A row without a readable date falls back to the time of the scrape
+        let undated = &competitions[1];
+        assert_eq!(undated.name, "Sustainability Challenge");
+        assert!(undated.date_unknown);
+        assert!(undated.date >= before && undated.date <= chrono::Utc::now());
+    }
+
+    /// Scraper that records how many scrapes are in flight at once
+    struct InFlightScraper {
+        name: String,
+        in_flight: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        peak: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Scraper for InFlightScraper {
+        async fn scrape(&self, _client: &reqwest::Client) -> Result<Vec<Competition>, Box<dyn std::error::Error + Send + Sync>> {
+            use std::sync::atomic::Ordering;
+            
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Vec::new())
+        }
+        
+        fn name(&self) -> &str {
+            &self.name
+        }
+        
+        fn source_url(&self) -> &str {
+            "https://example.com"
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_scrapes_stay_within_the_limit() {
+        let max_concurrent = 2;
+        let in_flight = std::sync::Arc::default();
+        let peak = std::sync::Arc::<std::sync::atomic::AtomicUsize>::default();
+        
+        let mut manager = ScraperManager::new();
+        manager.set_concurrency(max_concurrent);
+        manager.set_jitter(Duration::ZERO);
+        let names: Vec<String> = (0..6).map(|i| format!("mock{i}")).collect();
+        for name in &names {
+            manager.register_scraper(Box::new(InFlightScraper {
+                name: name.clone(),
+                in_flight: std::sync::Arc::clone(&in_flight),
+                peak: std::sync::Arc::clone(&peak),
+            }));
+        }
+        
+        let results = futures_util::future::join_all(names.iter().map(|name| manager.preview_scraper(name))).await;
+        
+        assert!(results.iter().all(Result::is_ok));
+        let peak = peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak <= max_concurrent, "{peak} scrapers ran at once");
+        assert!(peak > 1, "scrapers should overlap up to the limit");
+    }
+
+    #[test]
+    fn stemmed_variants_share_words() {
+        let config = MatchConfig::default();
+        
+        assert_eq!(overlap_words("trading trades", &config), ["trade", "trade"]);
+        assert_eq!(overlap_words("finance", &config), ["financ"]);
+        assert_eq!(overlap_words("financial", &config), ["financi"]);
+        assert!(fuzzy_match("Finance Competition", "Financial Challenge", &config));
+        assert!(fuzzy_match("Global Finance Cup", "Global Financial Cup", &config));
+    }
+
+    fn competition(name: &str, date: &str) -> Competition {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "date": date,
+            "host": "HKU",
+            "source": "HKU",
+        }))
+        .expect("valid competition")
+    }
+
+    #[test]
+    fn low_information_names_never_merge() {
+        let config = MatchConfig::default();
+        let briefing = competition("Information Session [HKU]", "2024-03-01T00:00:00Z");
+        let other_briefing = competition("Info Session - Announcement", "2024-03-01T00:00:00Z");
+        
+        assert!(is_low_information_name(&briefing.name, &config));
+        assert!(is_low_information_name(&other_briefing.name, &config));
+        assert!(!fuzzy_match(&briefing.name, &briefing.name, &config));
+        assert!(!is_same_event(&briefing, &other_briefing, &config));
+        assert!(!is_low_information_name("Information Security Hackathon", &config));
+    }
+
+    #[test]
+    fn dates_with_milliseconds_still_use_the_date_window() {
+        let config = MatchConfig::default();
+        let this_year = competition("Global Fintech Cup", "2024-03-01T00:00:00.123Z");
+        let last_year = competition("Global Fintech Cup", "2023-03-01T00:00:00.456Z");
+        
+        assert!(!is_same_event(&this_year, &last_year, &config));
+        
+        let mut undated = competition("Global Fintech Cup", "2024-10-15T08:00:00Z");
+        undated.date_unknown = true;
+        assert!(is_same_event(&undated, &last_year, &config));
+    }
+
+    /// Connect to the MongoDB server at `MONGODB_URI` and open a database unique to this test
+    async fn test_database() -> mongodb::Database {
+        let uri = std::env::var("MONGODB_URI").unwrap_or_else(|_| "mongodb://localhost:27017".to_string());
+        let client = mongodb::Client::with_uri_str(&uri).await.expect("MongoDB client");
+        client.database(&format!("competitions_test_{}", ObjectId::new().to_hex()))
+    }
+
+    fn test_state(db: mongodb::Database) -> AppState {
+        AppState {
+            db,
+            scrapers: std::sync::Arc::new(ScraperManager::new()),
+            notifier: Notifier::new(reqwest::Client::new(), None, crate::notify::NotifyTarget::Json),
+            pagination: crate::competitions::PaginationConfig::default(),
+            max_featured: 0,
+            metrics: std::sync::Arc::default(),
+            mailer: crate::mailer::Mailer::disabled(),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a MongoDB server at MONGODB_URI"]
+    async fn fuzzy_duplicates_are_recorded_as_merged() {
+        let db = test_database().await;
+        let state = test_state(db.clone());
+        let stored = competition("Global Finance Case Competition", "2026-03-01T00:00:00Z");
+        let rescraped = competition("Global Finance Case Competition 2026", "2026-03-01T00:00:00Z");
+        assert!(fuzzy_match(&rescraped.name, &stored.name, &MatchConfig::default()));
+        db.collection::<Competition>("competitions").insert_one(stored).await.expect("insert");
+        
+        let started_at = chrono::Utc::now();
+        let mut report = ScraperRunReport::new("mock".to_string());
+        let scraped = vec![rescraped, competition("Robotics Design Expo", "2026-04-01T00:00:00Z")];
+        let saved = deduplicate_and_save(&state, scraped, &mut report).await;
+        record_scraper_run(&db, &report, started_at).await;
+        let run = db
+            .collection::<ScraperRun>("scraper_runs")
+            .find_one(doc! { "scraper": "mock" })
+            .await;
+        db.drop().await.expect("drop test database");
+        
+        saved.expect("save scraped competitions");
+        let run = run.expect("find run").expect("run recorded");
+        assert_eq!((run.scraped, run.inserted, run.merged), (2, 1, 1));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a MongoDB server at MONGODB_URI"]
+    async fn empty_scrape_after_a_recorded_run_fails() {
+        let db = test_database().await;
+        let scraper = |name: &str| InFlightScraper {
+            name: name.to_string(),
+            in_flight: std::sync::Arc::default(),
+            peak: std::sync::Arc::default(),
+        };
+        let mut report = ScraperRunReport::new("mock".to_string());
+        report.scraped = 3;
+        record_scraper_run(&db, &report, chrono::Utc::now()).await;
+        
+        let drifted = ScraperManager::check_not_empty(&db, &scraper("Mock"), Vec::new()).await;
+        let never_found = ScraperManager::check_not_empty(&db, &scraper("other"), Vec::new()).await;
+        db.drop().await.expect("drop test database");
+        
+        let error = drifted.expect_err("empty scrape should fail");
+        assert!(error.to_string().contains("after finding 3"), "{error}");
+        assert!(never_found.expect("no earlier run").is_empty());
+    }
+
+    #[test]
+    fn candidate_filter_finds_stemmed_variants() {
+        let config = MatchConfig::default();
+        let scraped = competition("Finance Competition", "2026-03-01T00:00:00Z");
+        let stored = competition("Financial Challenge", "2025-03-01T00:00:00Z");
+        
+        assert!(candidate_matches(&candidate_filter(&scraped, &config), &stored));
+        assert!(fuzzy_match(&scraped.name, &stored.name, &config));
+        assert_eq!(name_keywords("Company Cup", &config), ["compan", "cup"]);
+    }
+
+    /// Evaluate a filter built by `candidate_filter` against a stored competition, as MongoDB would
+    fn candidate_matches(filter: &Document, stored: &Competition) -> bool {
+        if filter.is_empty() {
+            return true;
+        }
+        let stored = mongodb::bson::to_document(stored).expect("serializable competition");
+        
+        filter.get_array("$or").expect("$or clauses").iter().any(|clause| {
+            let (field, condition) = clause.as_document().and_then(|c| c.iter().next()).expect("one field");
+            let value = stored.get(field);
+            match condition {
+                mongodb::bson::Bson::Document(condition) if condition.contains_key("$regex") => {
+                    let pattern = condition.get_str("$regex").expect("regex pattern");
+                    let regex = regex::Regex::new(&format!("(?i){pattern}")).expect("valid regex");
+                    value.and_then(|v| v.as_str()).is_some_and(|v| regex.is_match(v))
+                }
+                mongodb::bson::Bson::Document(range) => value.and_then(|v| v.as_str()).is_some_and(|v| {
+                    v >= range.get_str("$gte").expect("lower bound") && v <= range.get_str("$lte").expect("upper bound")
+                }),
+                expected => value == Some(expected),
+            }
+        })
+    }
+
+    #[test]
+    fn candidate_filter_covers_typos_in_the_date_window() {
+        let config = MatchConfig::default();
+        let scraped = competition("Blokchain", "2026-03-01T00:00:00Z");
+        let filter = candidate_filter(&scraped, &config);
+        let nearby = competition("Blockchain", "2026-03-05T00:00:00.250Z");
+        let next_year = competition("Blockchain", "2027-03-01T00:00:00Z");
+        let mut undated = competition("Blockchain", "2020-01-01T00:00:00Z");
+        undated.date_unknown = true;
+        
+        assert!(fuzzy_match(&scraped.name, &nearby.name, &config));
+        assert!(candidate_matches(&filter, &nearby));
+        assert!(candidate_matches(&filter, &undated));
+        assert!(!candidate_matches(&filter, &next_year));
+        
+        let mut placeholder = scraped.clone();
+        placeholder.date_unknown = true;
+        assert!(candidate_filter(&placeholder, &config).is_empty());
+    }
 }
@@ -0,0 +1,203 @@
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Json},
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::TryStreamExt;
+use mongodb::bson::{doc, Document};
+use serde::{Deserialize, Serialize};
+
+use crate::{competitions::get_competition_collection, error::AppError, AppState, ApiResponse};
+
+/// Number of competitions sharing a host, status or source
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CountEntry {
+    #[serde(rename(deserialize = "_id"))]
+    pub key: String,
+    pub count: u64,
+}
+
+/// The competition whose signup deadline closes next
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NextDeadline {
+    #[serde(rename(deserialize = "_id"), serialize_with = "mongodb::bson::serde_helpers::serialize_object_id_as_hex_string")]
+    pub id: mongodb::bson::oid::ObjectId,
+    pub name: String,
+    pub signup_deadline: String,
+}
+
+/// Summary of the competition collection for dashboards
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatsResponse {
+    pub total: u64,
+    pub by_host: Vec<CountEntry>,
+    pub by_status: Vec<CountEntry>,
+    pub by_source: Vec<CountEntry>,
+    pub next_signup_deadline: Option<NextDeadline>,
+}
+
+/// Aggregation expression mirroring `Competition::computed_status`, comparing RFC3339 strings
+pub(crate) fn computed_status_expression(now: &DateTime<Utc>) -> Document {
+    let now_str = now.to_rfc3339();
+    let day_ago = (*now - Duration::days(1)).to_rfc3339();
+
+    doc! {
+        "$switch": {
+            "branches": [
+                { "case": { "$eq": ["$status", "cancelled"] }, "then": "cancelled" },
+                { "case": { "$lt": [&now_str, "$date"] }, "then": "upcoming" },
+                {
+                    "case": {
+                        "$or": [
+                            { "$and": [
+                                { "$eq": [{ "$type": "$signup_deadline" }, "string"] },
+                                { "$lte": [&now_str, "$signup_deadline"] },
+                            ] },
+                            { "$gt": ["$date", &day_ago] },
+                        ]
+                    },
+                    "then": "active",
+                },
+            ],
+            "default": "completed",
+        }
+    }
+}
+
+/// Group stage counting documents by `key`, sorted by count then key
+fn count_by(key: impl Into<mongodb::bson::Bson>) -> Vec<Document> {
+    vec![
+        doc! { "$group": { "_id": key.into(), "count": { "$sum": 1 } } },
+        doc! { "$sort": { "count": -1, "_id": 1 } },
+    ]
+}
+
+/// Stages turning each document into one `{source}` document per trimmed, non-empty source token
+fn split_sources_stages() -> Vec<Document> {
+    // Sources are stored comma-joined, so split them before grouping by each one
+    vec![
+        doc! { "$project": { "source": { "$split": ["$source", ","] } } },
+        doc! { "$unwind": "$source" },
+        doc! { "$project": { "source": { "$trim": { "input": "$source" } } } },
+        doc! { "$match": { "source": { "$ne": "" } } },
+    ]
+}
+
+/// Host and source lists change only when scrapers run, so let clients and proxies reuse them briefly
+const FILTER_VALUES_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Get counts by host, computed status and source, plus the next signup deadline
+pub async fn get_competition_stats(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<StatsResponse>>, AppError> {
+    let now = Utc::now();
+
+    let mut by_source = split_sources_stages();
+    by_source.extend(count_by("$source"));
+
+    let pipeline = vec![
+        // Archived competitions are left out, matching the list endpoint
+        doc! { "$match": { "deleted_at": null } },
+        doc! {
+            "$facet": {
+                "total": [{ "$count": "count" }],
+                "by_host": count_by("$host"),
+                "by_status": count_by(computed_status_expression(&now)),
+                "by_source": by_source,
+                "next_signup_deadline": [
+                    { "$match": { "signup_deadline": { "$gte": now.to_rfc3339() } } },
+                    { "$sort": { "signup_deadline": 1 } },
+                    { "$limit": 1 },
+                    { "$project": { "name": 1, "signup_deadline": 1 } },
+                ],
+            }
+        },
+        doc! {
+            "$project": {
+                "total": { "$ifNull": [{ "$arrayElemAt": ["$total.count", 0] }, 0] },
+                "by_host": 1,
+                "by_status": 1,
+                "by_source": 1,
+                "next_signup_deadline": { "$arrayElemAt": ["$next_signup_deadline", 0] },
+            }
+        },
+    ];
+
+    let document = get_competition_collection(&state)
+        .aggregate(pipeline)
+        .await
+        .map_err(|e| AppError::database("Error aggregating competition stats", e))?
+        .try_next()
+        .await
+        .map_err(|e| AppError::database("Error reading competition stats", e))?
+        .unwrap_or_default();
+
+    let stats: StatsResponse = mongodb::bson::from_document(document)
+        .map_err(|e| AppError::database("Error decoding competition stats", e))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(stats),
+        message: Some("Competition stats retrieved successfully".to_string()),
+    }))
+}
+
+/// Get the sorted distinct hosts of competitions that are not archived
+pub async fn get_competition_hosts(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    let mut hosts: Vec<String> = get_competition_collection(&state)
+        .distinct("host", doc! { "deleted_at": null })
+        .await
+        .map_err(|e| AppError::database("Error listing competition hosts", e))?
+        .into_iter()
+        .filter_map(|host| host.as_str().map(str::to_string))
+        .collect();
+    hosts.sort();
+
+    Ok((
+        [(header::CACHE_CONTROL, FILTER_VALUES_CACHE_CONTROL)],
+        Json(ApiResponse {
+            success: true,
+            data: Some(hosts),
+            message: Some("Competition hosts retrieved successfully".to_string()),
+        }),
+    ))
+}
+
+/// Get the sorted distinct source tokens of competitions that are not archived
+pub async fn get_competition_sources(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    let mut pipeline = vec![doc! { "$match": { "deleted_at": null } }];
+    pipeline.extend(split_sources_stages());
+    pipeline.push(doc! { "$group": { "_id": "$source" } });
+    pipeline.push(doc! { "$sort": { "_id": 1 } });
+
+    let sources: Vec<String> = get_competition_collection(&state)
+        .aggregate(pipeline)
+        .await
+        .map_err(|e| AppError::database("Error aggregating competition sources", e))?
+        .try_collect::<Vec<Document>>()
+        .await
+        .map_err(|e| AppError::database("Error reading competition sources", e))?
+        .into_iter()
+        .filter_map(|document| document.get_str("_id").ok().map(str::to_string))
+        .collect();
+
+    Ok((
+        [(header::CACHE_CONTROL, FILTER_VALUES_CACHE_CONTROL)],
+        Json(ApiResponse {
+            success: true,
+            data: Some(sources),
+            message: Some("Competition sources retrieved successfully".to_string()),
+        }),
+    ))
+}
+
+/// Create the router for statistics routes, nested under /competitions
+pub fn create_stats_router() -> Router<AppState> {
+    Router::new()
+        .route("/stats", get(get_competition_stats))
+        .route("/hosts", get(get_competition_hosts))
+        .route("/sources", get(get_competition_sources))
+}